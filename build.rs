@@ -0,0 +1,34 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Mirrors the `pdal_2_<minor>` cfg flags emitted by `pdal-sys` for the detected PDAL version, so
+//! APIs depending on newer PDAL releases can be gated in this crate too.
+
+fn main() {
+    let known = std::env::var("DEP_PDAL_KNOWN_CFGS").unwrap_or_default();
+    for cfg in known.split(',').filter(|c| !c.is_empty()) {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+    }
+    let enabled = std::env::var("DEP_PDAL_CFGS").unwrap_or_default();
+    for cfg in enabled.split(',').filter(|c| !c.is_empty()) {
+        println!("cargo:rustc-cfg={cfg}");
+    }
+    println!("cargo:rerun-if-env-changed=DEP_PDAL_CFGS");
+    println!("cargo:rerun-if-env-changed=DEP_PDAL_KNOWN_CFGS");
+}
@@ -5,23 +5,21 @@ use std::error::Error;
 fn main() -> Result<(), Box<dyn Error>> {
     // get filename from args
     let filename = std::env::args().nth(1).expect("missing filename argument");
-    let pipeline_json = format!(
-        r#"
-        {{
+    let pipeline_json = r#"
+        {
             "pipeline": [
-                {{
-                    "type": "readers.las",
-                    "filename": "{filename}"
-                }},
-                {{
+                {
+                    "type": "readers.las"
+                },
+                {
                     "type": "writers.null"
-                }}
+                }
             ]
-        }}
-    "#
-    );
+        }
+    "#;
 
-    let pipeline = Pipeline::new(pipeline_json)?;
+    let mut pipeline = Pipeline::new(pipeline_json)?;
+    pipeline.set_reader_filename(filename)?;
     let results = pipeline.execute()?;
 
     let views = results.point_views()?;
@@ -18,32 +18,78 @@
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use cxx_build::CFG;
+use std::collections::HashMap;
 use std::error::Error;
-use std::path::PathBuf;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
 
-static MODULES: &[&str] = &["core", "config", "pipeline_manager", "layout", "point_view"];
+static MODULES: &[&str] = &[
+    "core",
+    "config",
+    "pipeline_manager",
+    "layout",
+    "point_view",
+    "reader",
+    "kd_index",
+];
+
+// `pdal::Dimension::BaseType` flags. Encoding ordinals are computed by PDAL as `base | size`.
+const SIGNED: u32 = 0x100;
+const UNSIGNED: u32 = 0x200;
+const FLOATING: u32 = 0x400;
+
+/// `pdal::Dimension::Type` variants as `(name, base type, size in bytes)`.
+static ENCODINGS: &[(&str, u32, u32)] = &[
+    ("Unsigned8", UNSIGNED, 1),
+    ("Signed8", SIGNED, 1),
+    ("Unsigned16", UNSIGNED, 2),
+    ("Signed16", SIGNED, 2),
+    ("Unsigned32", UNSIGNED, 4),
+    ("Signed32", SIGNED, 4),
+    ("Unsigned64", UNSIGNED, 8),
+    ("Signed64", SIGNED, 8),
+    ("Float", FLOATING, 4),
+    ("Double", FLOATING, 8),
+];
+
+/// Oldest PDAL release supported, as `(major, minor)`.
+const MIN_VERSION: (u32, u32) = (2, 4);
+
+/// PDAL 2.x minor releases for which a `pdal_2_<minor>` cfg flag is emitted when the detected
+/// version is at least that release.
+static VERSION_CFGS: &[u32] = &[4, 5, 6, 7, 8];
+
+/// Static libraries produced by the bundled PDAL build, in link order (dependents before their
+/// dependencies). Those not produced by a given PDAL version are skipped.
+#[cfg(feature = "bundled")]
+static BUNDLED_LIBS: &[&str] = &[
+    "pdalcpp",
+    "pdal_util",
+    "pdal_lepcc",
+    "pdal_kazhdan",
+    "pdal_arbiter",
+    "lazperf_s",
+    "lazperf",
+];
+
+/// Dimensions the bindings can't function without.
+static REQUIRED_DIMS: &[&str] = &["Unknown", "X", "Y", "Z"];
 
 // See https://github.com/alexcrichton/curl-rust/blob/0.4.34/curl-sys/build.rs
 // for inspiration.
 fn main() -> Result<(), Box<dyn Error>> {
     // std::env::set_var("RUST_LOG", "bindgen=info");
     // let _ = env_logger::builder().is_test(true).try_init()?;
-    let mut pdal_pkg_config = pkg_config::Config::new().probe("pdal")?;
-
-    // For some reason pkg-config reports a path like `/foo/bar/include/pdal`, but
-    // internal C++ references assume that the path is `/foo/bar/include`.
-    if let Some(pdal_inc) = pdal_pkg_config
-        .include_paths
-        .iter()
-        .find(|&path| path.ends_with("pdal"))
-    {
-        if let Some(parent) = pdal_inc.parent() {
-            pdal_pkg_config.include_paths.push(parent.to_path_buf());
-        }
-    }
+    let pdal = find_pdal()?;
 
     CFG.exported_header_dirs
-        .extend(pdal_pkg_config.include_paths.iter().map(|p| p.as_path()));
+        .extend(pdal.include_paths.iter().map(|p| p.as_path()));
+
+    emit_version_cfgs(&pdal.version)?;
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    let dim_header = find_header(&pdal.include_paths, "pdal/Dimension.hpp")?;
+    let enum_checks = generate_enums(&dim_header, &out_dir)?;
 
     let module_files = MODULES
         .iter()
@@ -58,6 +104,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     for m in MODULES {
         builder.file(format!("src/{m}/{m}.cpp"));
     }
+    builder.file(enum_checks);
 
     builder.compile("pdal-sys");
 
@@ -66,6 +113,446 @@ fn main() -> Result<(), Box<dyn Error>> {
         println!("cargo:rerun-if-changed=src/{m}/{m}.cpp");
         println!("cargo:rerun-if-changed=src/{m}/{m}.hpp");
     }
+    println!("cargo:rerun-if-changed={}", dim_header.display());
 
     Ok(())
 }
+
+/// Location and version of the PDAL installation being built against.
+struct PdalInstall {
+    include_paths: Vec<PathBuf>,
+    version: String,
+}
+
+/// Locate the system PDAL via `pkg-config`, which also emits the required linker flags.
+#[cfg(not(feature = "bundled"))]
+fn find_pdal() -> Result<PdalInstall, Box<dyn Error>> {
+    let mut pdal_pkg_config = match pkg_config::Config::new().probe("pdal") {
+        Ok(lib) => lib,
+        Err(pkg_config::Error::Command { cause, .. })
+            if cause.kind() == std::io::ErrorKind::NotFound =>
+        {
+            return Err(
+                "pdal-sys: `pkg-config` was not found on the PATH. It is required to \
+                locate the system PDAL installation; install it along with the PDAL development \
+                package, or enable the `bundled` feature to build PDAL from source."
+                    .into(),
+            )
+        }
+        Err(e) => {
+            return Err(format!(
+                "pdal-sys: unable to locate PDAL via `pkg-config`. Install the PDAL development \
+                package (e.g. `libpdal-dev`), set `PKG_CONFIG_PATH` to the directory containing \
+                `pdal.pc`, or enable the `bundled` feature to build PDAL from source.\n\n{e}"
+            )
+            .into())
+        }
+    };
+
+    // For some reason pkg-config reports a path like `/foo/bar/include/pdal`, but
+    // internal C++ references assume that the path is `/foo/bar/include`.
+    if let Some(pdal_inc) = pdal_pkg_config
+        .include_paths
+        .iter()
+        .find(|&path| path.ends_with("pdal"))
+    {
+        if let Some(parent) = pdal_inc.parent() {
+            pdal_pkg_config.include_paths.push(parent.to_path_buf());
+        }
+    }
+
+    Ok(PdalInstall {
+        include_paths: pdal_pkg_config.include_paths,
+        version: pdal_pkg_config.version,
+    })
+}
+
+/// Build PDAL from the vendored source tree with CMake, and link it statically.
+///
+/// PDAL's own vendored dependencies (e.g. laz-perf) are compiled in, but GDAL and PROJ are
+/// required by PDAL and are still linked from the system.
+#[cfg(feature = "bundled")]
+fn find_pdal() -> Result<PdalInstall, Box<dyn Error>> {
+    println!("cargo:rerun-if-env-changed=PDAL_SYS_SOURCE_DIR");
+    let src = match std::env::var_os("PDAL_SYS_SOURCE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var("CARGO_MANIFEST_DIR")?).join("vendor/PDAL"),
+    };
+    if !src.join("CMakeLists.txt").is_file() {
+        return Err(format!(
+            "pdal-sys: the `bundled` feature requires the PDAL source tree at `{}`. Populate it \
+            with `git submodule update --init pdal-sys/vendor/PDAL`, or set \
+            `PDAL_SYS_SOURCE_DIR` to an existing checkout.",
+            src.display()
+        )
+        .into());
+    }
+
+    let dst = cmake::Config::new(&src)
+        .define("PDAL_BUILD_STATIC", "ON")
+        .define("BUILD_SHARED_LIBS", "OFF")
+        .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+        .define("WITH_TESTS", "OFF")
+        .define("WITH_COMPLETION", "OFF")
+        .define("WITH_ZSTD", "OFF")
+        .define("WITH_LZMA", "OFF")
+        .build();
+
+    for lib_dir in ["lib", "lib64"]
+        .map(|d| dst.join(d))
+        .iter()
+        .filter(|d| d.is_dir())
+    {
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    }
+    let lib_path = |lib: &str| {
+        ["lib", "lib64"]
+            .iter()
+            .map(|d| dst.join(d).join(format!("lib{lib}.a")))
+            .find(|p| p.is_file())
+    };
+    if lib_path(BUNDLED_LIBS[0]).is_none() {
+        return Err(format!(
+            "pdal-sys: the bundled PDAL build didn't produce `lib{}.a`",
+            BUNDLED_LIBS[0]
+        )
+        .into());
+    }
+    for lib in BUNDLED_LIBS.iter().filter(|lib| lib_path(lib).is_some()) {
+        println!("cargo:rustc-link-lib=static={lib}");
+    }
+    for dep in ["gdal", "proj"] {
+        if pkg_config::Config::new().probe(dep).is_err() {
+            println!("cargo:rustc-link-lib={dep}");
+        }
+    }
+
+    let include = dst.join("include");
+    let features = std::fs::read_to_string(include.join("pdal/pdal_features.hpp"))?;
+    let version = features
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("#define PDAL_VERSION_STRING"))
+        .map(|v| v.trim().trim_matches('"').to_string())
+        .ok_or("pdal-sys: unable to determine version of bundled PDAL")?;
+
+    Ok(PdalInstall {
+        include_paths: vec![include.join("pdal"), include],
+        version,
+    })
+}
+
+/// Emits `pdal_2_<minor>` cfg flags for the detected PDAL version, and exports them to dependent
+/// crates as `DEP_PDAL_CFGS` (enabled) and `DEP_PDAL_KNOWN_CFGS` (all possible).
+///
+/// Version-dependent dimensions are generated from the headers instead, so no bindings are
+/// currently gated on these; see the README.
+fn emit_version_cfgs(version: &str) -> Result<(), Box<dyn Error>> {
+    let mut parts = version.split('.').map(|p| p.parse::<u32>());
+    let (major, minor) = match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => (major, minor),
+        _ => return Err(format!("pdal-sys: unable to parse PDAL version '{version}'").into()),
+    };
+    if (major, minor) < MIN_VERSION {
+        return Err(format!(
+            "pdal-sys: PDAL {version} is not supported; version {}.{} or newer is required",
+            MIN_VERSION.0, MIN_VERSION.1
+        )
+        .into());
+    }
+
+    let known = VERSION_CFGS
+        .iter()
+        .map(|m| format!("pdal_2_{m}"))
+        .collect::<Vec<_>>();
+    let enabled = VERSION_CFGS
+        .iter()
+        .zip(&known)
+        .filter(|(&m, _)| major > 2 || minor >= m)
+        .map(|(_, cfg)| cfg.as_str())
+        .collect::<Vec<_>>();
+    for cfg in &known {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+    }
+    for cfg in &enabled {
+        println!("cargo:rustc-cfg={cfg}");
+    }
+    println!("cargo:rustc-env=PDAL_SYS_BUILD_VERSION={version}");
+    println!("cargo:version={version}");
+    println!("cargo:cfgs={}", enabled.join(","));
+    println!("cargo:known_cfgs={}", known.join(","));
+    Ok(())
+}
+
+fn find_header(include_paths: &[PathBuf], name: &str) -> Result<PathBuf, Box<dyn Error>> {
+    include_paths
+        .iter()
+        .map(|p| p.join(name))
+        .find(|p| p.is_file())
+        .ok_or_else(|| {
+            format!(
+                "pdal-sys: unable to find `{name}` in the PDAL include paths {include_paths:?}. \
+                Is the PDAL development package installed?"
+            )
+            .into()
+        })
+}
+
+/// A standard dimension parsed from PDAL's `Dimension.hpp`, itself generated from `Dimension.json`.
+struct DimSpec {
+    name: String,
+    value: i32,
+    description: Option<String>,
+}
+
+/// Generates `DimTypeId` and `DimTypeEncoding` into `$OUT_DIR/enums.rs`, along with a C++
+/// source file of `static_assert`s verifying them against the PDAL headers. Returns the path
+/// to the latter.
+fn generate_enums(dim_header: &Path, out_dir: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let header = std::fs::read_to_string(dim_header)?;
+    let dims = parse_dimensions(&header).map_err(|e| {
+        format!(
+            "pdal-sys: incompatible PDAL version; failed to read dimensions from `{}`: {e}",
+            dim_header.display()
+        )
+    })?;
+
+    let mut rs = String::new();
+    let mut cpp = String::new();
+    writeln!(
+        rs,
+        "// Generated by pdal-sys/build.rs from `{}`.",
+        dim_header.display()
+    )?;
+    writeln!(rs)?;
+    writeln!(rs, "/// Standard PDAL dimension types.")?;
+    writeln!(rs, "#[derive(Clone, Copy, PartialEq, Eq, Default, Hash)]")?;
+    writeln!(rs, "#[repr(C)]")?;
+    writeln!(rs, "#[non_exhaustive]")?;
+    writeln!(rs, "pub enum DimTypeId {{")?;
+    for d in &dims {
+        if let Some(desc) = &d.description {
+            writeln!(rs, "    /// {}", doc_escape(desc))?;
+        }
+        if d.name == "Unknown" {
+            writeln!(rs, "    #[default]")?;
+        }
+        writeln!(rs, "    {} = {},", d.name, d.value)?;
+    }
+    writeln!(rs, "}}")?;
+    writeln!(rs)?;
+    writeln!(rs, "impl DimTypeId {{")?;
+    writeln!(
+        rs,
+        "    /// All standard dimensions known to the compiled-against PDAL version."
+    )?;
+    writeln!(rs, "    pub const ALL: &'static [DimTypeId] = &[")?;
+    for d in &dims {
+        writeln!(rs, "        DimTypeId::{},", d.name)?;
+    }
+    writeln!(rs, "    ];")?;
+    writeln!(rs)?;
+    writeln!(
+        rs,
+        "    /// Convert from a raw `pdal::Dimension::Id` value."
+    )?;
+    writeln!(
+        rs,
+        "    pub(crate) fn from_raw(raw: i32) -> Option<DimTypeId> {{"
+    )?;
+    writeln!(rs, "        match raw {{")?;
+    for d in &dims {
+        writeln!(
+            rs,
+            "            {} => Some(DimTypeId::{}),",
+            d.value, d.name
+        )?;
+    }
+    writeln!(rs, "            _ => None,")?;
+    writeln!(rs, "        }}")?;
+    writeln!(rs, "    }}")?;
+    writeln!(rs, "}}")?;
+    writeln!(rs)?;
+    writeln!(
+        rs,
+        "/// Encoding of a dimension's values, as defined by `pdal::Dimension::Type`."
+    )?;
+    writeln!(rs, "#[derive(Clone, Copy, PartialEq, Eq, Default)]")?;
+    writeln!(rs, "#[repr(C)]")?;
+    writeln!(rs, "pub enum DimTypeEncoding {{")?;
+    writeln!(rs, "    #[default]")?;
+    writeln!(rs, "    None = 0,")?;
+    for &(name, base, size) in ENCODINGS {
+        writeln!(rs, "    {name} = {},", base | size)?;
+    }
+    writeln!(rs, "}}")?;
+
+    writeln!(cpp, "// Generated by pdal-sys/build.rs.")?;
+    writeln!(
+        cpp,
+        "// Fails compilation if the generated Rust enums disagree with the PDAL headers."
+    )?;
+    writeln!(cpp, "#include <pdal/pdal.hpp>")?;
+    writeln!(cpp)?;
+    for d in &dims {
+        writeln!(
+            cpp,
+            "static_assert(static_cast<int>(pdal::Dimension::Id::{0}) == {1}, \
+            \"pdal-sys: DimTypeId::{0} doesn't match pdal::Dimension::Id::{0}\");",
+            d.name, d.value
+        )?;
+    }
+    for &(name, base, size) in ENCODINGS {
+        writeln!(
+            cpp,
+            "static_assert(static_cast<int>(pdal::Dimension::Type::{0}) == {1}, \
+            \"pdal-sys: DimTypeEncoding::{0} doesn't match pdal::Dimension::Type::{0}; \
+            this version of PDAL is not supported\");",
+            name,
+            base | size
+        )?;
+    }
+
+    std::fs::write(out_dir.join("enums.rs"), rs)?;
+    let checks = out_dir.join("enum_checks.cpp");
+    std::fs::write(&checks, cpp)?;
+    Ok(checks)
+}
+
+fn parse_dimensions(header: &str) -> Result<Vec<DimSpec>, String> {
+    let header = strip_comments(header);
+    let body = block_after(&header, "enum class Id").ok_or("`enum class Id` not found")?;
+    let descriptions = switch_strings(&header, "description(Id id)");
+
+    let mut dims = Vec::new();
+    let mut next = 0;
+    for entry in body.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, value) = match entry.split_once('=') {
+            Some((name, value)) => {
+                let value = value.trim();
+                let value = value
+                    .parse::<i32>()
+                    .map_err(|_| format!("unsupported value `{value}` for `{name}`"))?;
+                (name.trim(), value)
+            }
+            None => (entry, next),
+        };
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("unexpected enumerator `{entry}`"));
+        }
+        next = value + 1;
+        dims.push(DimSpec {
+            name: name.to_string(),
+            value,
+            description: descriptions.get(name).cloned(),
+        });
+    }
+
+    for &required in REQUIRED_DIMS {
+        if !dims.iter().any(|d| d.name == required) {
+            return Err(format!("required dimension `{required}` not defined"));
+        }
+    }
+    if dims.iter().find(|d| d.name == "Unknown").map(|d| d.value) != Some(0) {
+        return Err("expected `Unknown` dimension to have value 0".into());
+    }
+    Ok(dims)
+}
+
+/// Remove `//` and `/* */` comments.
+fn strip_comments(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut rest = src;
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix("//") {
+            rest = r.find('\n').map_or("", |i| &r[i..]);
+        } else if let Some(r) = rest.strip_prefix("/*") {
+            rest = r.find("*/").map_or("", |i| &r[i + 2..]);
+        } else if rest.starts_with('"') {
+            let len = string_literal(rest).map_or(rest.len(), |(_, len)| len);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+        } else {
+            let c = rest.chars().next().unwrap();
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Contents of the first brace-delimited block following `marker`.
+fn block_after<'a>(src: &'a str, marker: &str) -> Option<&'a str> {
+    let start = src.find(marker)? + marker.len();
+    let open = start + src[start..].find('{')?;
+    let mut depth = 0;
+    for (i, c) in src[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&src[open + 1..open + i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Map of `case Id::<name>: return "<string>";` entries in the function with the given signature.
+fn switch_strings(src: &str, signature: &str) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let Some(body) = block_after(src, signature) else {
+        return map;
+    };
+    for case in body.split("case Id::").skip(1) {
+        let Some((name, rest)) = case.split_once(':') else {
+            continue;
+        };
+        let Some(ret) = rest.find("return") else {
+            continue;
+        };
+        // Adjacent string literals are concatenated, as in C++.
+        let mut value = String::new();
+        let mut rest = rest[ret + "return".len()..].trim_start();
+        while let Some((lit, len)) = string_literal(rest) {
+            value.push_str(&lit);
+            rest = rest[len..].trim_start();
+        }
+        if !value.is_empty() {
+            map.insert(name.trim().to_string(), value);
+        }
+    }
+    map
+}
+
+/// Parse a C++ string literal at the start of `src`, returning its unescaped contents and
+/// length in bytes.
+fn string_literal(src: &str) -> Option<(String, usize)> {
+    let mut chars = src.char_indices();
+    if chars.next()?.1 != '"' {
+        return None;
+    }
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, i + 1)),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push(' '),
+                e => value.push(e),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Escape text so rustdoc renders it verbatim.
+fn doc_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -65,6 +65,12 @@ mod ffi {
 
 pub use ffi::*;
 
+/// Version of PDAL the bindings were compiled against, as reported by `pkg-config`.
+///
+/// May differ from [`version_string`] if the PDAL library loaded at runtime differs from the one
+/// present at build time.
+pub const BUILD_VERSION: &str = env!("PDAL_SYS_BUILD_VERSION");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +84,22 @@ mod tests {
         assert_eq!(ver_int, major * 10000 + minor * 100 + patch);
     }
 
+    #[test]
+    fn test_build_version() {
+        let mut parts = BUILD_VERSION.split('.').map(|p| p.parse::<i32>().unwrap());
+        assert_eq!(parts.next(), Some(version_major()));
+        assert_eq!(parts.next(), Some(version_minor()));
+    }
+
+    #[test]
+    fn test_version_cfgs() {
+        let minor = version_minor();
+        assert_eq!(cfg!(pdal_2_5), minor >= 5);
+        assert_eq!(cfg!(pdal_2_6), minor >= 6);
+        assert_eq!(cfg!(pdal_2_7), minor >= 7);
+        assert_eq!(cfg!(pdal_2_8), minor >= 8);
+    }
+
     #[test]
     fn test_version_string() {
         let full_version = full_version_string();
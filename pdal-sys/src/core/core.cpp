@@ -36,6 +36,9 @@ namespace pdal_sys {
         rust::String name(DimTypeId id) {
             return rust::String { pdal::Dimension::name(id) };
         }
+        DimTypeId idFromName(rust::Str name) {
+            return pdal::Dimension::id(std::string(name));
+        }
 
         DimTypeEncoding encoding(const DimType& id) {
             return id.m_type;
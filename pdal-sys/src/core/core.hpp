@@ -58,6 +58,7 @@ namespace pdal_sys {
         DimTypeId id(const DimType &dt);
         rust::String description(DimTypeId id);
         rust::String name(DimTypeId id);
+        DimTypeId idFromName(rust::Str name);
 
         using DimTypeRepr = pdal::Dimension::Type;
         DimTypeEncoding encoding(const DimType &dt);
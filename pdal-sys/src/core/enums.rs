@@ -17,168 +17,8 @@
 // DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-/// Standard PDAL dimension types.
-// NB: this was hand copied. In the future we should use `bindgen` to ensure future compatibility
-#[derive(Clone, Copy, PartialEq, Eq, Default, Hash)]
-#[repr(C)]
-#[non_exhaustive]
-pub enum DimTypeId {
-    #[default]
-    Unknown,
-    X,
-    Y,
-    Z,
-    W,
-    Intensity,
-    Amplitude,
-    Reflectance,
-    ReturnNumber,
-    NumberOfReturns,
-    ScanDirectionFlag,
-    EdgeOfFlightLine,
-    Classification,
-    ScanAngleRank,
-    UserData,
-    PointSourceId,
-    Red,
-    Green,
-    Blue,
-    GpsTime,
-    InternalTime,
-    OffsetTime,
-    IsPpsLocked,
-    StartPulse,
-    ReflectedPulse,
-    Pdop,
-    Pitch,
-    Roll,
-    PulseWidth,
-    Deviation,
-    PassiveSignal,
-    BackgroundRadiation,
-    PassiveX,
-    PassiveY,
-    PassiveZ,
-    XVelocity,
-    YVelocity,
-    ZVelocity,
-    Azimuth,
-    WanderAngle,
-    XBodyAccel,
-    YBodyAccel,
-    ZBodyAccel,
-    XBodyAngRate,
-    YBodyAngRate,
-    ZBodyAngRate,
-    Flag,
-    Mark,
-    Alpha,
-    EchoRange,
-    ScanChannel,
-    Infrared,
-    HeightAboveGround,
-    ClassFlags,
-    Synthetic,
-    KeyPoint,
-    Withheld,
-    Overlap,
-    LvisLfid,
-    ShotNumber,
-    LongitudeCentroid,
-    LatitudeCentroid,
-    ElevationCentroid,
-    LongitudeLow,
-    LatitudeLow,
-    ElevationLow,
-    LongitudeHigh,
-    LatitudeHigh,
-    ElevationHigh,
-    PointId,
-    OriginId,
-    NormalX,
-    NormalY,
-    NormalZ,
-    Curvature,
-    Density,
-    Omit,
-    ClusterID,
-    NNDistance,
-    TextureU,
-    TextureV,
-    TextureW,
-    Linearity,
-    Planarity,
-    Scattering,
-    Verticality,
-    Omnivariance,
-    Anisotropy,
-    Eigenentropy,
-    EigenvalueSum,
-    SurfaceVariation,
-    DemantkeVerticality,
-    OptimalKNN,
-    OptimalRadius,
-    Coplanar,
-    LocalReachabilityDistance,
-    LocalOutlierFactor,
-    Miniball,
-    Reciprocity,
-    Rank,
-    Eigenvalue0,
-    Eigenvalue1,
-    Eigenvalue2,
-    PlaneFit,
-    RadialDensity,
-    BeamOriginX,
-    BeamOriginY,
-    BeamOriginZ,
-    BeamDirectionX,
-    BeamDirectionY,
-    BeamDirectionZ,
-    NorthPositionRMS,
-    EastPositionRMS,
-    DownPositionRMS,
-    NorthVelocityRMS,
-    EastVelocityRMS,
-    DownVelocityRMS,
-    RollRMS,
-    PitchRMS,
-    HeadingRMS,
-    Reliability,
-    EchoPos,
-    EchoNorm,
-    ImgNbr,
-    Image,
-    Dimension,
-    SphericalRange,
-    SphericalAzimuth,
-    SphericalElevation,
-}
-
-unsafe impl cxx::ExternType for DimTypeId {
-    type Id = cxx::type_id!("pdal_sys::core::DimTypeId");
-    type Kind = cxx::kind::Trivial;
-}
-
-// NB: this was hand copied. Ordinals are from pdal::Dimension::Type, and are
-// compile-time computed.
-// In the future we should use `bindgen` to ensure stable compatibility
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
-#[repr(C)]
-pub enum DimTypeEncoding {
-    #[default]
-    None = 0,
-    Unsigned8 = 513,
-    Signed8 = 257,
-    Unsigned16 = 514,
-    Signed16 = 258,
-    Unsigned32 = 516,
-    Signed32 = 260,
-    Unsigned64 = 520,
-    Signed64 = 264,
-    Float = 1028,
-    Double = 1032,
-}
+// `DimTypeId` and `DimTypeEncoding` are generated by `build.rs` from the PDAL headers.
+include!(concat!(env!("OUT_DIR"), "/enums.rs"));
 
 /// Enables copy by value
 unsafe impl cxx::ExternType for DimTypeEncoding {
@@ -194,14 +34,17 @@ pub trait PdalType: Sized {
     /// Convert into a wrapped PdalValue
     fn into_pdal_value(self) -> PdalValue;
 
-    /// Convert dynamic type to static type when logically known.
-    /// Returns `None` if given value isn't actually the <u>exact</u> same
-    /// type as encoding.
+    /// Convert from a wrapped PdalValue. Returns `None` if the value can't be represented
+    /// exactly as `Self`.
+    fn from_pdal_value(value: PdalValue) -> Option<Self>;
+
+    /// Convert between types, e.g. a `u16` into a `u32`.
+    /// Returns `None` if the value can't be represented exactly as `Self`.
     fn static_cast<T: PdalType>(value: T) -> Option<Self> {
-        if Self::encoding() == T::encoding() {
+        if Self::encoding() == T::encoding() && Self::encoding() != DimTypeEncoding::None {
             Some(unsafe { std::mem::transmute_copy::<T, Self>(&value) })
         } else {
-            None
+            Self::from_pdal_value(value.into_pdal_value())
         }
     }
 }
@@ -215,6 +58,9 @@ macro_rules! impl_pdal_type {
             fn into_pdal_value(self) -> PdalValue {
                 PdalValue::$enc(self)
             }
+            fn from_pdal_value(value: PdalValue) -> Option<Self> {
+                value.checked_cast()
+            }
         }
     };
     () => {};
@@ -274,4 +120,8 @@ impl PdalType for PdalValue {
     fn into_pdal_value(self) -> PdalValue {
         self
     }
+
+    fn from_pdal_value(value: PdalValue) -> Option<Self> {
+        Some(value)
+    }
 }
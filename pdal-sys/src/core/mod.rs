@@ -20,8 +20,12 @@
 #![allow(dead_code)]
 
 mod enums;
+#[cfg(feature = "serde")]
+mod serde;
+mod value;
 
 pub use enums::*;
+pub use value::*;
 
 #[cxx::bridge(namespace = "pdal_sys")]
 mod ffi {
@@ -31,7 +35,7 @@ mod ffi {
         include!("pdal-sys/src/core/core.hpp");
         // TODO: See github.com/dtolnay/cxx/issues/1332
         type DimType;
-        type DimTypeId = super::enums::DimTypeId;
+        type DimTypeId = super::Dimension;
         fn id(dt: &DimType) -> DimTypeId;
         type DimTypeEncoding = super::enums::DimTypeEncoding;
         fn encoding(dt: &DimType) -> DimTypeEncoding;
@@ -42,6 +46,7 @@ mod ffi {
         #[cxx_name = "name"]
         fn idName(id: DimTypeId) -> String;
         fn description(id: DimTypeId) -> String;
+        fn idFromName(name: &str) -> DimTypeId;
         type DimTypeIter<'a>;
         fn hasNext(self: &DimTypeIter) -> bool;
         fn next(self: Pin<&mut DimTypeIter>) -> Result<&DimType>;
@@ -64,6 +69,7 @@ mod ffi {
 use cxx::UniquePtr;
 pub(crate) use ffi::DimTypeIter;
 pub use ffi::{pdal_sys_throw, DimIdIter, DimType, DimTypeEncoding};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::mem;
@@ -71,9 +77,90 @@ use std::mem;
 /// Unique identifier for a point in a point view.
 pub type PointId = u64;
 
+/// Identifier of a dimension, covering both the standard dimensions enumerated in [`DimTypeId`]
+/// and proprietary dimensions registered at runtime (e.g. LAS extra bytes, or dimensions created
+/// by `filters.assign`/`filters.ferry`).
+///
+/// Proprietary identifiers are only meaningful in the context of the point layout they were
+/// registered with.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Dimension(i32);
+
+/// Enables copy by value. Binary compatible with `pdal::Dimension::Id`.
+unsafe impl cxx::ExternType for Dimension {
+    type Id = cxx::type_id!("pdal_sys::core::DimTypeId");
+    type Kind = cxx::kind::Trivial;
+}
+
+impl Dimension {
+    /// Get the corresponding standard dimension, or `None` if this is a proprietary dimension.
+    pub fn standard(&self) -> Option<DimTypeId> {
+        DimTypeId::from_raw(self.0)
+    }
+
+    /// Determine if this is a standard dimension.
+    pub fn is_standard(&self) -> bool {
+        self.standard().is_some()
+    }
+
+    /// Raw PDAL identifier value.
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+
+    /// Name of the dimension if it is a standard one. Proprietary dimension names are only
+    /// available through the point layout they're registered with.
+    pub fn name(&self) -> Option<String> {
+        self.standard().map(|id| id.name())
+    }
+}
+
+impl From<DimTypeId> for Dimension {
+    fn from(id: DimTypeId) -> Self {
+        Dimension(id as i32)
+    }
+}
+
+impl PartialEq<DimTypeId> for Dimension {
+    fn eq(&self, other: &DimTypeId) -> bool {
+        self.0 == *other as i32
+    }
+}
+
+impl PartialEq<Dimension> for DimTypeId {
+    fn eq(&self, other: &Dimension) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<DimTypeId> for Dimension {
+    fn partial_cmp(&self, other: &DimTypeId) -> Option<Ordering> {
+        Some(self.0.cmp(&(*other as i32)))
+    }
+}
+
+impl Debug for Dimension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.standard() {
+            Some(id) => Debug::fmt(&id, f),
+            None => write!(f, "Dimension({})", self.0),
+        }
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.standard() {
+            Some(id) => Display::fmt(&id, f),
+            None => write!(f, "Dimension({})", self.0),
+        }
+    }
+}
+
 impl DimType {
     #[inline]
-    pub fn id(&self) -> DimTypeId {
+    pub fn id(&self) -> Dimension {
         ffi::id(self)
     }
     #[inline]
@@ -94,11 +181,20 @@ impl Debug for DimType {
 impl DimTypeId {
     #[inline]
     pub fn name(&self) -> String {
-        ffi::idName(*self)
+        ffi::idName((*self).into())
     }
     #[inline]
     pub fn description(&self) -> String {
-        ffi::description(*self)
+        ffi::description((*self).into())
+    }
+    /// Lookup a standard dimension by its (case-insensitive) name.
+    ///
+    /// Returns `None` if PDAL doesn't define a dimension with the given name.
+    pub fn from_name(name: &str) -> Option<DimTypeId> {
+        match ffi::idFromName(name).standard() {
+            Some(DimTypeId::Unknown) | None => None,
+            id => id,
+        }
     }
 }
 
@@ -161,7 +257,7 @@ impl Debug for DimTypeEncoding {
 pub struct DimIdIterator(pub(crate) UniquePtr<DimIdIter>);
 
 impl Iterator for DimIdIterator {
-    type Item = DimTypeId;
+    type Item = Dimension;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.0.hasNextId() {
@@ -197,11 +293,30 @@ impl<'a> Iterator for DimTypeIterator<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::core::DimTypeId;
+    use crate::core::{DimTypeId, Dimension};
 
     #[test]
     fn test_get_views() {
         assert_eq!(DimTypeId::Anisotropy.name(), "Anisotropy");
         assert!(DimTypeId::Anisotropy.description().contains("variance"));
     }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            DimTypeId::from_name("Intensity"),
+            Some(DimTypeId::Intensity)
+        );
+        assert_eq!(DimTypeId::from_name("gpstime"), Some(DimTypeId::GpsTime));
+        assert_eq!(DimTypeId::from_name("NotADimension"), None);
+    }
+
+    #[test]
+    fn test_dimension() {
+        let dim = Dimension::from(DimTypeId::Classification);
+        assert_eq!(dim, DimTypeId::Classification);
+        assert_eq!(dim.standard(), Some(DimTypeId::Classification));
+        assert_eq!(dim.name().as_deref(), Some("Classification"));
+        assert_eq!(dim.to_string(), "Classification");
+    }
 }
@@ -0,0 +1,112 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! `PdalValue` is serialized as its bare primitive value. On deserialization the variant is
+//! inferred from the primitive type provided by the format, so self-describing formats with
+//! sized integers (e.g. MessagePack, CBOR) round-trip exactly, while JSON integers become
+//! `Unsigned64`/`Signed64` and JSON floats `Double`.
+
+use super::PdalValue;
+use serde::de::{Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt::Formatter;
+
+impl Serialize for PdalValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            PdalValue::None => serializer.serialize_none(),
+            PdalValue::Unsigned8(v) => serializer.serialize_u8(v),
+            PdalValue::Signed8(v) => serializer.serialize_i8(v),
+            PdalValue::Unsigned16(v) => serializer.serialize_u16(v),
+            PdalValue::Signed16(v) => serializer.serialize_i16(v),
+            PdalValue::Unsigned32(v) => serializer.serialize_u32(v),
+            PdalValue::Signed32(v) => serializer.serialize_i32(v),
+            PdalValue::Unsigned64(v) => serializer.serialize_u64(v),
+            PdalValue::Signed64(v) => serializer.serialize_i64(v),
+            PdalValue::Float(v) => serializer.serialize_f32(v),
+            PdalValue::Double(v) => serializer.serialize_f64(v),
+        }
+    }
+}
+
+struct PdalValueVisitor;
+
+macro_rules! visit {
+    ($name:ident, $t:ty, $variant:ident) => {
+        fn $name<E>(self, v: $t) -> Result<PdalValue, E> {
+            Ok(PdalValue::$variant(v))
+        }
+    };
+}
+
+impl<'de> Visitor<'de> for PdalValueVisitor {
+    type Value = PdalValue;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("a numeric value or null")
+    }
+
+    visit!(visit_u8, u8, Unsigned8);
+    visit!(visit_i8, i8, Signed8);
+    visit!(visit_u16, u16, Unsigned16);
+    visit!(visit_i16, i16, Signed16);
+    visit!(visit_u32, u32, Unsigned32);
+    visit!(visit_i32, i32, Signed32);
+    visit!(visit_u64, u64, Unsigned64);
+    visit!(visit_i64, i64, Signed64);
+    visit!(visit_f32, f32, Float);
+    visit!(visit_f64, f64, Double);
+
+    fn visit_none<E>(self) -> Result<PdalValue, E> {
+        Ok(PdalValue::None)
+    }
+
+    fn visit_unit<E>(self) -> Result<PdalValue, E> {
+        Ok(PdalValue::None)
+    }
+}
+
+impl<'de> Deserialize<'de> for PdalValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PdalValueVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::PdalValue;
+
+    #[test]
+    fn test_json() {
+        let values = [
+            PdalValue::Unsigned64(7),
+            PdalValue::Signed64(-3),
+            PdalValue::Double(1.5),
+            PdalValue::None,
+        ];
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, "[7,-3,1.5,null]");
+        let back: Vec<PdalValue> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, values);
+
+        let json = serde_json::to_string(&PdalValue::Unsigned16(12)).unwrap();
+        assert_eq!(json, "12");
+        assert!(serde_json::from_str::<PdalValue>("\"12\"").is_err());
+    }
+}
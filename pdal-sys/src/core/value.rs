@@ -0,0 +1,348 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversions, formatting and ordering for [`PdalValue`].
+
+use super::{DimTypeEncoding, PdalType, PdalValue};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Numeric content of a [`PdalValue`], wide enough to hold any variant exactly.
+#[derive(Debug, Clone, Copy)]
+enum Num {
+    Int(i128),
+    Float(f64),
+}
+
+impl PdalValue {
+    fn num(self) -> Option<Num> {
+        Some(match self {
+            PdalValue::None => return None,
+            PdalValue::Unsigned8(v) => Num::Int(v.into()),
+            PdalValue::Signed8(v) => Num::Int(v.into()),
+            PdalValue::Unsigned16(v) => Num::Int(v.into()),
+            PdalValue::Signed16(v) => Num::Int(v.into()),
+            PdalValue::Unsigned32(v) => Num::Int(v.into()),
+            PdalValue::Signed32(v) => Num::Int(v.into()),
+            PdalValue::Unsigned64(v) => Num::Int(v.into()),
+            PdalValue::Signed64(v) => Num::Int(v.into()),
+            PdalValue::Float(v) => Num::Float(v.into()),
+            PdalValue::Double(v) => Num::Float(v),
+        })
+    }
+
+    /// Get the encoding matching the variant.
+    pub fn encoding(&self) -> DimTypeEncoding {
+        match self {
+            PdalValue::None => DimTypeEncoding::None,
+            PdalValue::Unsigned8(_) => DimTypeEncoding::Unsigned8,
+            PdalValue::Signed8(_) => DimTypeEncoding::Signed8,
+            PdalValue::Unsigned16(_) => DimTypeEncoding::Unsigned16,
+            PdalValue::Signed16(_) => DimTypeEncoding::Signed16,
+            PdalValue::Unsigned32(_) => DimTypeEncoding::Unsigned32,
+            PdalValue::Signed32(_) => DimTypeEncoding::Signed32,
+            PdalValue::Unsigned64(_) => DimTypeEncoding::Unsigned64,
+            PdalValue::Signed64(_) => DimTypeEncoding::Signed64,
+            PdalValue::Float(_) => DimTypeEncoding::Float,
+            PdalValue::Double(_) => DimTypeEncoding::Double,
+        }
+    }
+
+    /// Determine if this is [`PdalValue::None`], i.e. holds no value.
+    pub fn is_none(&self) -> bool {
+        matches!(self, PdalValue::None)
+    }
+
+    /// Convert to `T` if the value can be represented exactly, e.g. `Unsigned16(300)` as `u32`
+    /// or `Double(4.0)` as `u8`, but not `Signed8(-1)` as `u8` or `Double(0.5)` as `i32`.
+    pub fn checked_cast<T: PdalPrimitive>(self) -> Option<T> {
+        T::checked_from(self)
+    }
+
+    /// Convert to `T`, clamping to its range. Floating point values are truncated toward zero
+    /// when converted to integers, and `NaN` and `None` become zero.
+    pub fn saturating_cast<T: PdalPrimitive>(self) -> T {
+        T::saturating_from(self)
+    }
+}
+
+macro_rules! impl_as {
+    ($($name:ident => $t:ty),*) => {
+        impl PdalValue {
+            $(
+                #[doc = concat!("Convert to `", stringify!($t), "` if the value can be represented exactly.")]
+                pub fn $name(self) -> Option<$t> {
+                    self.checked_cast()
+                }
+            )*
+        }
+    };
+}
+
+impl_as!(
+    as_u8 => u8, as_i8 => i8, as_u16 => u16, as_i16 => i16, as_u32 => u32,
+    as_i32 => i32, as_u64 => u64, as_i64 => i64, as_f32 => f32, as_f64 => f64
+);
+
+/// Primitive types a [`PdalValue`] can be converted into.
+pub trait PdalPrimitive: PdalType + Copy + sealed::Sealed {
+    /// Exact conversion, or `None` if the value can't be represented.
+    fn checked_from(value: PdalValue) -> Option<Self>;
+    /// Conversion clamping to the range of `Self`.
+    fn saturating_from(value: PdalValue) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Convert an integral float to `i128`, if it fits.
+fn integral(f: f64) -> Option<i128> {
+    // 2^127 is exactly representable, so the bounds check is exact.
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0;
+    (f.fract() == 0.0 && (-LIMIT..LIMIT).contains(&f)).then_some(f as i128)
+}
+
+macro_rules! impl_int_primitive {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+        impl PdalPrimitive for $t {
+            fn checked_from(value: PdalValue) -> Option<Self> {
+                match value.num()? {
+                    Num::Int(i) => <$t>::try_from(i).ok(),
+                    Num::Float(f) => <$t>::try_from(integral(f)?).ok(),
+                }
+            }
+
+            fn saturating_from(value: PdalValue) -> Self {
+                match value.num() {
+                    None => 0,
+                    Some(Num::Int(i)) => i.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t,
+                    // `as` saturates and maps NaN to zero.
+                    Some(Num::Float(f)) => f as $t,
+                }
+            }
+        }
+    )*};
+}
+
+impl_int_primitive!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+macro_rules! impl_float_primitive {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+        impl PdalPrimitive for $t {
+            fn checked_from(value: PdalValue) -> Option<Self> {
+                match value.num()? {
+                    Num::Int(i) => {
+                        let f = i as $t;
+                        (f as i128 == i).then_some(f)
+                    }
+                    Num::Float(f) => {
+                        let v = f as $t;
+                        (f.is_nan() || v as f64 == f).then_some(v)
+                    }
+                }
+            }
+
+            fn saturating_from(value: PdalValue) -> Self {
+                match value.num() {
+                    None => 0.0,
+                    Some(Num::Int(i)) => i as $t,
+                    Some(Num::Float(f)) if f.is_finite() => f.clamp(<$t>::MIN as f64, <$t>::MAX as f64) as $t,
+                    Some(Num::Float(f)) => f as $t,
+                }
+            }
+        }
+    )*};
+}
+
+impl_float_primitive!(f32, f64);
+
+/// Error returned when a [`PdalValue`] can't be represented exactly in the requested type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConversionError {
+    /// The value that couldn't be converted.
+    pub value: PdalValue,
+    /// Encoding of the requested type.
+    pub target: DimTypeEncoding,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} can't be represented as {:?}",
+            self.value, self.target
+        )
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+macro_rules! impl_conversions {
+    ($($t:ty),*) => {$(
+        impl From<$t> for PdalValue {
+            fn from(value: $t) -> Self {
+                value.into_pdal_value()
+            }
+        }
+
+        impl TryFrom<PdalValue> for $t {
+            type Error = ConversionError;
+
+            fn try_from(value: PdalValue) -> Result<Self, Self::Error> {
+                value.checked_cast().ok_or(ConversionError {
+                    value,
+                    target: <$t as PdalType>::encoding(),
+                })
+            }
+        }
+    )*};
+}
+
+impl_conversions!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl Display for PdalValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PdalValue::None => f.write_str("None"),
+            PdalValue::Unsigned8(v) => v.fmt(f),
+            PdalValue::Signed8(v) => v.fmt(f),
+            PdalValue::Unsigned16(v) => v.fmt(f),
+            PdalValue::Signed16(v) => v.fmt(f),
+            PdalValue::Unsigned32(v) => v.fmt(f),
+            PdalValue::Signed32(v) => v.fmt(f),
+            PdalValue::Unsigned64(v) => v.fmt(f),
+            PdalValue::Signed64(v) => v.fmt(f),
+            PdalValue::Float(v) => v.fmt(f),
+            PdalValue::Double(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Exact comparison of an integer with a float. `None` if `f` is `NaN`.
+fn cmp_int_float(i: i128, f: f64) -> Option<Ordering> {
+    if f.is_nan() {
+        return None;
+    }
+    let floor = f.floor();
+    Some(match integral(floor) {
+        // `f` is beyond the range of `i128`.
+        None if floor > 0.0 => Ordering::Less,
+        None => Ordering::Greater,
+        Some(fi) => i.cmp(&fi).then(if f > floor {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }),
+    })
+}
+
+/// Values are ordered numerically regardless of variant, with `None` before any number.
+/// Numerically equal values of different variants are ordered by encoding, consistent with
+/// `PartialEq`.
+impl PartialOrd for PdalValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let numeric = match (self.num(), other.num()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(Num::Int(a)), Some(Num::Int(b))) => a.cmp(&b),
+            (Some(Num::Float(a)), Some(Num::Float(b))) => a.partial_cmp(&b)?,
+            (Some(Num::Int(a)), Some(Num::Float(b))) => cmp_int_float(a, b)?,
+            (Some(Num::Float(a)), Some(Num::Int(b))) => cmp_int_float(b, a)?.reverse(),
+        };
+        Some(numeric.then((self.encoding() as i32).cmp(&(other.encoding() as i32))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{ConversionError, DimTypeEncoding, PdalType, PdalValue};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_checked_cast() {
+        let v = PdalValue::Unsigned16(300);
+        assert_eq!(v.as_u32(), Some(300));
+        assert_eq!(v.as_f32(), Some(300.0));
+        assert_eq!(v.as_u8(), None);
+        assert_eq!(PdalValue::Signed8(-1).as_u64(), None);
+        assert_eq!(PdalValue::Double(4.0).as_u8(), Some(4));
+        assert_eq!(PdalValue::Double(0.5).as_i32(), None);
+        assert_eq!(PdalValue::Double(0.1).as_f32(), None);
+        assert_eq!(PdalValue::Unsigned64(u64::MAX).as_f64(), None);
+        assert_eq!(PdalValue::None.as_i64(), None);
+
+        let r: Result<u16, _> = PdalValue::Signed32(70000).try_into();
+        assert_eq!(
+            r,
+            Err(ConversionError {
+                value: PdalValue::Signed32(70000),
+                target: DimTypeEncoding::Unsigned16
+            })
+        );
+        assert_eq!(u32::try_from(PdalValue::Unsigned16(12)), Ok(12));
+        assert_eq!(PdalValue::from(3u8), PdalValue::Unsigned8(3));
+        assert_eq!(u32::static_cast(7u16), Some(7));
+        assert_eq!(u8::static_cast(PdalValue::Signed16(-7)), None);
+        assert_eq!(PdalValue::static_cast(2.5f32), Some(PdalValue::Float(2.5)));
+    }
+
+    #[test]
+    fn test_saturating_cast() {
+        assert_eq!(PdalValue::Signed16(-5).saturating_cast::<u8>(), 0);
+        assert_eq!(PdalValue::Unsigned32(1000).saturating_cast::<u8>(), 255);
+        assert_eq!(PdalValue::Double(-1e300).saturating_cast::<i64>(), i64::MIN);
+        assert_eq!(PdalValue::Double(2.9).saturating_cast::<i32>(), 2);
+        assert_eq!(PdalValue::Double(f64::NAN).saturating_cast::<u16>(), 0);
+        assert_eq!(PdalValue::Double(1e300).saturating_cast::<f32>(), f32::MAX);
+        assert_eq!(PdalValue::None.saturating_cast::<f64>(), 0.0);
+    }
+
+    #[test]
+    fn test_display_and_order() {
+        assert_eq!(PdalValue::Signed8(-4).to_string(), "-4");
+        assert_eq!(PdalValue::Double(1.5).to_string(), "1.5");
+        assert_eq!(
+            PdalValue::Unsigned8(3).encoding(),
+            DimTypeEncoding::Unsigned8
+        );
+
+        assert!(PdalValue::Unsigned8(3) < PdalValue::Signed64(4));
+        assert!(PdalValue::Signed64(-1) < PdalValue::Unsigned8(0));
+        assert!(PdalValue::Double(2.5) > PdalValue::Unsigned16(2));
+        assert!(PdalValue::Double(2.5) < PdalValue::Unsigned16(3));
+        assert!(PdalValue::Unsigned64(u64::MAX) < PdalValue::Double(1e30));
+        assert!(PdalValue::None < PdalValue::Signed8(i8::MIN));
+        assert_eq!(
+            PdalValue::Unsigned8(5).partial_cmp(&PdalValue::Unsigned8(5)),
+            Some(Ordering::Equal)
+        );
+        assert_ne!(
+            PdalValue::Unsigned8(5).partial_cmp(&PdalValue::Unsigned16(5)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            PdalValue::Double(f64::NAN).partial_cmp(&PdalValue::Double(0.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,87 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#include "pdal-sys/src/kd_index/kd_index.hpp"
+#include "pdal-sys/src/kd_index/mod.rs.h"
+
+namespace pdal_sys {
+    namespace kd_index {
+        namespace {
+            rust::Vec<std::uint64_t> toVec(const pdal::PointIdList& ids) {
+                rust::Vec<std::uint64_t> result;
+                result.reserve(ids.size());
+                for (auto id : ids) {
+                    result.push_back(id);
+                }
+                return result;
+            }
+
+            void checkPoint(const PointView& view, std::uint64_t idx) {
+                if (idx >= view.size()) {
+                    throw pdal::pdal_error("Point " + std::to_string(idx) + " is out of range for a view of " +
+                        std::to_string(view.size()) + " points");
+                }
+            }
+        }
+
+        std::unique_ptr<KD2Index> createKD2Index(const std::shared_ptr<PointView>& view) {
+            return std::unique_ptr<KD2Index>(new KD2Index(view));
+        }
+
+        std::unique_ptr<KD3Index> createKD3Index(const std::shared_ptr<PointView>& view) {
+            return std::unique_ptr<KD3Index>(new KD3Index(view));
+        }
+
+        KD2Index::KD2Index(std::shared_ptr<PointView> view) :
+                m_view(std::move(view)), m_index(*m_view) {
+            m_index.build();
+        }
+
+        rust::Vec<std::uint64_t> KD2Index::radius(double x, double y, double r) const {
+            return toVec(m_index.radius(x, y, r));
+        }
+
+        rust::Vec<std::uint64_t> KD2Index::neighbors(std::uint64_t idx, std::uint64_t k) const {
+            checkPoint(*m_view, idx);
+            return toVec(m_index.neighbors(idx, k));
+        }
+
+        rust::Vec<std::uint64_t> KD2Index::knn(double x, double y, std::uint64_t k) const {
+            return toVec(m_index.neighbors(x, y, k));
+        }
+
+        KD3Index::KD3Index(std::shared_ptr<PointView> view) :
+                m_view(std::move(view)), m_index(*m_view) {
+            m_index.build();
+        }
+
+        rust::Vec<std::uint64_t> KD3Index::radius(double x, double y, double z, double r) const {
+            return toVec(m_index.radius(x, y, z, r));
+        }
+
+        rust::Vec<std::uint64_t> KD3Index::neighbors(std::uint64_t idx, std::uint64_t k) const {
+            checkPoint(*m_view, idx);
+            return toVec(m_index.neighbors(idx, k));
+        }
+
+        rust::Vec<std::uint64_t> KD3Index::knn(double x, double y, double z, std::uint64_t k) const {
+            return toVec(m_index.neighbors(x, y, z, k));
+        }
+    }
+}
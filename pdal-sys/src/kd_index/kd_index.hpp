@@ -0,0 +1,59 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#pragma once
+#include "rust/cxx.h"
+#include <pdal/pdal.hpp>
+#include <pdal/KDIndex.hpp>
+#include <memory>
+
+namespace pdal_sys {
+    namespace kd_index {
+        using PointView = pdal::PointView;
+
+        /// 2D (X/Y) spatial index over a point view. Keeps the view alive.
+        class KD2Index {
+        public:
+            explicit KD2Index(std::shared_ptr<PointView> view);
+            rust::Vec<std::uint64_t> radius(double x, double y, double r) const;
+            rust::Vec<std::uint64_t> neighbors(std::uint64_t idx, std::uint64_t k) const;
+            rust::Vec<std::uint64_t> knn(double x, double y, std::uint64_t k) const;
+
+        private:
+            std::shared_ptr<PointView> m_view;
+            pdal::KD2Index m_index;
+        };
+
+        /// 3D (X/Y/Z) spatial index over a point view. Keeps the view alive.
+        class KD3Index {
+        public:
+            explicit KD3Index(std::shared_ptr<PointView> view);
+            rust::Vec<std::uint64_t> radius(double x, double y, double z, double r) const;
+            rust::Vec<std::uint64_t> neighbors(std::uint64_t idx, std::uint64_t k) const;
+            rust::Vec<std::uint64_t> knn(double x, double y, double z, std::uint64_t k) const;
+
+        private:
+            std::shared_ptr<PointView> m_view;
+            pdal::KD3Index m_index;
+        };
+
+        std::unique_ptr<KD2Index> createKD2Index(const std::shared_ptr<PointView>& view);
+        std::unique_ptr<KD3Index> createKD3Index(const std::shared_ptr<PointView>& view);
+    }
+}
@@ -0,0 +1,105 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cxx::bridge(namespace = "pdal_sys")]
+mod ffi {
+    #[namespace = "pdal_sys::kd_index"]
+    unsafe extern "C++" {
+        include!("pdal-sys/src/kd_index/kd_index.hpp");
+        #[namespace = "pdal_sys::point_view"]
+        type PointView = crate::point_view::PointView;
+
+        type KD2Index;
+        fn createKD2Index(view: &SharedPtr<PointView>) -> Result<UniquePtr<KD2Index>>;
+        fn radius(self: &KD2Index, x: f64, y: f64, r: f64) -> Result<Vec<u64>>;
+        fn neighbors(self: &KD2Index, idx: u64, k: u64) -> Result<Vec<u64>>;
+        fn knn(self: &KD2Index, x: f64, y: f64, k: u64) -> Result<Vec<u64>>;
+
+        type KD3Index;
+        fn createKD3Index(view: &SharedPtr<PointView>) -> Result<UniquePtr<KD3Index>>;
+        fn radius(self: &KD3Index, x: f64, y: f64, z: f64, r: f64) -> Result<Vec<u64>>;
+        fn neighbors(self: &KD3Index, idx: u64, k: u64) -> Result<Vec<u64>>;
+        fn knn(self: &KD3Index, x: f64, y: f64, z: f64, k: u64) -> Result<Vec<u64>>;
+    }
+}
+pub use ffi::{createKD2Index, createKD3Index, KD2Index, KD3Index};
+
+use cxx::UniquePtr;
+
+pub type KD2IndexPtr = UniquePtr<KD2Index>;
+pub type KD3IndexPtr = UniquePtr<KD3Index>;
+
+// SAFETY: The indexes are immutable once built, and queries only read the index and the
+// (shared, read-only) point view it was built from.
+unsafe impl Send for KD2Index {}
+unsafe impl Sync for KD2Index {}
+unsafe impl Send for KD3Index {}
+unsafe impl Sync for KD3Index {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DimTypeEncoding;
+    use crate::point_view::{createPointViewBuilder, PointViewPtr};
+
+    /// 10x10 grid of points at integer X/Y, with Z equal to X.
+    fn grid() -> PointViewPtr {
+        let mut builder = createPointViewBuilder();
+        let x = builder
+            .pin_mut()
+            .registerDim("X", DimTypeEncoding::Double)
+            .unwrap();
+        let y = builder
+            .pin_mut()
+            .registerDim("Y", DimTypeEncoding::Double)
+            .unwrap();
+        let z = builder
+            .pin_mut()
+            .registerDim("Z", DimTypeEncoding::Double)
+            .unwrap();
+        let xs = (0..100).map(|i| (i % 10) as f64).collect::<Vec<_>>();
+        let ys = (0..100).map(|i| (i / 10) as f64).collect::<Vec<_>>();
+        builder.pin_mut().setFields_f64(x, &xs).unwrap();
+        builder.pin_mut().setFields_f64(y, &ys).unwrap();
+        builder.pin_mut().setFields_f64(z, &xs).unwrap();
+        builder.pin_mut().build().unwrap()
+    }
+
+    #[test]
+    fn test_kd2() {
+        let index = createKD2Index(&grid()).unwrap();
+        let mut ids = index.radius(5.0, 5.0, 1.1).unwrap();
+        ids.sort();
+        assert_eq!(ids, vec![45, 54, 55, 56, 65]);
+        assert_eq!(index.knn(0.1, 0.0, 1).unwrap(), vec![0]);
+        let ids = index.neighbors(0, 3).unwrap();
+        assert_eq!(ids[0], 0);
+        assert_eq!(ids.len(), 3);
+        assert!(index.neighbors(100, 3).is_err());
+    }
+
+    #[test]
+    fn test_kd3() {
+        let index = createKD3Index(&grid()).unwrap();
+        let mut ids = index.radius(5.0, 5.0, 5.0, 1.1).unwrap();
+        ids.sort();
+        assert_eq!(ids, vec![45, 55, 65]);
+        assert_eq!(index.knn(9.0, 9.0, 9.0, 1).unwrap(), vec![99]);
+    }
+}
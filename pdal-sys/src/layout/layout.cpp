@@ -32,5 +32,13 @@ namespace pdal_sys {
         std::size_t dimensionCount(const PointLayout &pl) {
             return pl.dims().size();
         }
+
+        pdal_sys::core::DimTypeId findDim(const PointLayout &pl, rust::Str name) {
+            return pl.findDim(std::string(name));
+        }
+
+        rust::String dimName(const PointLayout &pl, pdal_sys::core::DimTypeId id) {
+            return rust::String { pl.dimName(id) };
+        }
     }
 }
\ No newline at end of file
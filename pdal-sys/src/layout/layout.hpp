@@ -28,5 +28,7 @@ namespace pdal_sys {
         std::unique_ptr<pdal_sys::core::DimTypeIter> dimTypes(const PointLayout &ps);
         std::unique_ptr<pdal_sys::core::DimIdIter> dimIds(const PointLayout &ps);
         std::size_t dimensionCount(const PointLayout &pl);
+        pdal_sys::core::DimTypeId findDim(const PointLayout &pl, rust::Str name);
+        rust::String dimName(const PointLayout &pl, pdal_sys::core::DimTypeId id);
     }
 }
\ No newline at end of file
@@ -37,7 +37,7 @@ mod ffi {
         fn dimTypes<'pl>(pl: &'pl PointLayout) -> UniquePtr<DimTypeIter<'pl>>;
 
         #[namespace = "pdal_sys::core"]
-        type DimTypeId = crate::core::DimTypeId;
+        type DimTypeId = crate::core::Dimension;
         #[namespace = "pdal_sys::core"]
         type DimTypeEncoding = crate::core::DimTypeEncoding;
         fn dimOffset(self: &PointLayout, id: DimTypeId) -> usize;
@@ -48,6 +48,8 @@ mod ffi {
         #[namespace = "pdal_sys::core"]
         type DimIdIter = crate::core::DimIdIter;
         fn dimIds(pl: &PointLayout) -> UniquePtr<DimIdIter>;
+        fn findDim(pl: &PointLayout, name: &str) -> DimTypeId;
+        fn dimName(pl: &PointLayout, id: DimTypeId) -> String;
     }
 }
 pub use ffi::PointLayout;
@@ -65,6 +67,22 @@ impl PointLayout {
     pub fn dim_ids(&self) -> DimIdIterator {
         DimIdIterator(ffi::dimIds(self))
     }
+
+    /// Lookup a dimension registered with this layout by name, including proprietary dimensions.
+    pub fn find_dim(&self, name: &str) -> Option<Dimension> {
+        let dim = ffi::findDim(self, name);
+        if dim == DimTypeId::Unknown {
+            None
+        } else {
+            Some(dim)
+        }
+    }
+
+    /// Get the name of a dimension, as registered with this layout.
+    #[inline]
+    pub fn dim_name(&self, dim: Dimension) -> String {
+        ffi::dimName(self, dim)
+    }
 }
 
 impl Debug for PointLayout {
@@ -100,7 +118,12 @@ mod tests {
             layout
                 .dim_types()
                 .map(|dt| dt.encoding().size_bytes())
-                .sum()
+                .sum::<usize>()
         );
+
+        let dim = layout.find_dim("Intensity").expect("Intensity dimension");
+        assert_eq!(dim, crate::core::DimTypeId::Intensity);
+        assert_eq!(layout.dim_name(dim), "Intensity");
+        assert!(layout.find_dim("NotADimension").is_none());
     }
 }
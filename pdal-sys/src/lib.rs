@@ -21,9 +21,11 @@
 /// See the `pdal` crate for the idiomatic interface.
 pub mod config;
 pub mod core;
+pub mod kd_index;
 pub mod layout;
 pub mod pipeline_manager;
 pub mod point_view;
+pub mod reader;
 
 #[cfg(test)]
 pub(crate) mod testkit {
@@ -19,30 +19,92 @@
 
 #![allow(dead_code)]
 
-use cxx::UniquePtr;
+use cxx::{SharedPtr, UniquePtr};
 use std::fmt::Debug;
 
 #[cxx::bridge(namespace = "pdal_sys")]
 mod ffi {
-    
+    /// Single `name`/`value` stage option. Options may repeat.
+    #[derive(Debug, Clone)]
+    struct StageOption {
+        name: String,
+        value: String,
+    }
+
+    /// Description of a stage in a parsed pipeline.
+    #[derive(Debug, Clone)]
+    struct StageInfo {
+        /// Stage type name, e.g. `readers.las`.
+        name: String,
+        tag: String,
+        /// Indices of input stages, in the order returned by `PipelineManager::stages`.
+        inputs: Vec<usize>,
+        options: Vec<StageOption>,
+    }
+
     unsafe extern "C++" {
         include!("pdal-sys/src/pipeline_manager/pipeline_manager.hpp");
         type PipelineManager;
         #[namespace = "pdal_sys::point_view_set"]
         type PointViewSet = crate::point_view::PointViewSet;
+        #[namespace = "pdal_sys::point_view"]
+        type PointView = crate::point_view::PointView;
         fn createPipelineManager() -> UniquePtr<PipelineManager>;
         fn readPipeline(self: Pin<&mut PipelineManager>, pipeline: &str) -> Result<()>;
         fn readPipelineFromFile(self: Pin<&mut PipelineManager>, path: &str) -> Result<()>;
+        fn setOption(
+            self: Pin<&mut PipelineManager>,
+            stage: usize,
+            name: &str,
+            value: &str,
+        ) -> Result<()>;
+        /// Set the streaming point table capacity and standard-mode limits, where 0 is unlimited.
+        fn setLimits(
+            self: Pin<&mut PipelineManager>,
+            stream_chunk_size: usize,
+            max_points: u64,
+            max_bytes: u64,
+        ) -> Result<()>;
+        fn addInput(self: Pin<&mut PipelineManager>, view: &SharedPtr<PointView>) -> Result<()>;
         fn pipelineStreamable(self: &PipelineManager) -> bool;
+        /// Indices of stages that don't support stream mode.
+        fn nonStreamableStages(self: &PipelineManager) -> Vec<usize>;
         fn execute(self: Pin<&mut PipelineManager>) -> Result<usize>;
-        fn executeStreamed(self: Pin<&mut PipelineManager>) -> Result<()>;
+        /// Returns `true` if the pipeline was run in stream mode.
+        fn executeStreamed(self: Pin<&mut PipelineManager>) -> Result<bool>;
+        /// Points produced by each stage in the last execution, or -1 if not executed.
+        fn stagePointCounts(self: &PipelineManager) -> Vec<i64>;
+        /// Get a handle for observing or cancelling execution from another thread.
+        ///
+        /// Must be requested before execution to take effect.
+        fn monitor(self: Pin<&mut PipelineManager>) -> SharedPtr<ExecutionMonitor>;
+        /// Redirect the stages' Info level log output to the monitor, for `drainLog`.
+        fn captureLog(self: Pin<&mut PipelineManager>);
+
+        type ExecutionMonitor;
+        /// Abort execution at the next point processed.
+        fn cancel(self: &ExecutionMonitor);
+        fn isCancelled(self: &ExecutionMonitor) -> bool;
+        /// Points produced by each stage so far.
+        fn stagePoints(self: &ExecutionMonitor) -> Vec<u64>;
+        /// Take complete log lines written since the last call.
+        fn drainLog(self: &ExecutionMonitor) -> Vec<String>;
         fn views(self: &PipelineManager) -> Result<&PointViewSet>;
         fn metadata(self: &PipelineManager) -> Result<String>;
         fn schema(self: &PipelineManager) -> Result<String>;
         fn pipeline(self: &PipelineManager) -> Result<String>;
+        fn stages(self: &PipelineManager) -> Vec<StageInfo>;
+        fn roots(self: &PipelineManager) -> Vec<usize>;
+        fn leaves(self: &PipelineManager) -> Vec<usize>;
     }
 }
-pub use ffi::{createPipelineManager, PipelineManager};
+pub use ffi::{createPipelineManager, ExecutionMonitor, PipelineManager, StageInfo, StageOption};
+
+// SAFETY: The manager has no thread affinity; exclusive access is enforced by `Pin<&mut _>`.
+unsafe impl Send for PipelineManager {}
+// SAFETY: All `ExecutionMonitor` operations are atomic or mutex guarded.
+unsafe impl Send for ExecutionMonitor {}
+unsafe impl Sync for ExecutionMonitor {}
 
 impl PipelineManager {
     pub fn new() -> UniquePtr<ffi::PipelineManager> {
@@ -50,6 +112,7 @@ impl PipelineManager {
     }
 }
 pub type PipelineManagerPtr = UniquePtr<PipelineManager>;
+pub type ExecutionMonitorPtr = SharedPtr<ExecutionMonitor>;
 
 impl Debug for PipelineManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -90,4 +153,122 @@ mod tests {
         let r = mgr.pin_mut().execute();
         assert_eq!(r.unwrap(), 110000);
     }
+
+    #[test]
+    fn test_monitor() {
+        std::env::set_current_dir(TEST_WD.to_path_buf()).unwrap();
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut()
+            .readPipelineFromFile(&data_file_path("stats.json"))
+            .unwrap();
+        let monitor = mgr.pin_mut().monitor();
+        mgr.pin_mut().executeStreamed().unwrap();
+        assert_eq!(monitor.stagePoints(), vec![110000; 3]);
+        // The log isn't captured unless requested.
+        assert!(monitor.drainLog().is_empty());
+
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut()
+            .readPipelineFromFile(&data_file_path("stats.json"))
+            .unwrap();
+        let monitor = mgr.pin_mut().monitor();
+        monitor.cancel();
+        assert!(monitor.isCancelled());
+        let r = mgr.pin_mut().executeStreamed();
+        assert!(r.unwrap_err().to_string().contains("cancelled"));
+
+        // Standard mode stops within the reader, before it completes.
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut()
+            .readPipelineFromFile(&data_file_path("stats.json"))
+            .unwrap();
+        mgr.pin_mut().monitor().cancel();
+        let r = mgr.pin_mut().execute();
+        assert!(r.unwrap_err().to_string().contains("cancelled"));
+        assert_eq!(mgr.stagePointCounts()[0], 0);
+    }
+
+    #[test]
+    fn test_stage_point_counts() {
+        std::env::set_current_dir(TEST_WD.to_path_buf()).unwrap();
+        let json = r#"[ "tests/data/autzen_trim.laz",
+            { "type": "filters.range", "limits": "Classification[2:2]" },
+            { "type": "writers.null" } ]"#;
+        for streamed in [false, true] {
+            let mut mgr = createPipelineManager();
+            mgr.pin_mut().readPipeline(json).unwrap();
+            if streamed {
+                assert!(mgr.pin_mut().executeStreamed().unwrap());
+            } else {
+                mgr.pin_mut().execute().unwrap();
+            }
+            let counts = mgr.stagePointCounts();
+            assert_eq!(counts[0], 110000);
+            assert!(counts[1] > 0 && counts[1] < 110000, "{counts:?}");
+            assert_eq!(counts[2], counts[1]);
+            assert_eq!(mgr.leaves(), vec![2]);
+        }
+
+        // The reader feeds both branches, but its points are only counted once.
+        let json = r#"[
+            { "type": "readers.las", "filename": "tests/data/autzen_trim.laz", "tag": "r" },
+            { "type": "filters.range", "inputs": ["r"], "limits": "Classification[2:2]", "tag": "a" },
+            { "type": "filters.range", "inputs": ["r"], "limits": "Classification![2:2]", "tag": "b" },
+            { "type": "filters.merge", "inputs": ["a", "b"] } ]"#;
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut().readPipeline(json).unwrap();
+        assert_eq!(mgr.pin_mut().execute().unwrap(), 110000);
+        let counts = mgr.stagePointCounts();
+        assert_eq!(counts[0], 110000);
+        assert_eq!(counts[1] + counts[2], 110000);
+        assert_eq!(counts[3], 110000);
+    }
+
+    #[test]
+    fn test_limits() {
+        std::env::set_current_dir(TEST_WD.to_path_buf()).unwrap();
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut()
+            .readPipelineFromFile(&data_file_path("stats.json"))
+            .unwrap();
+        assert!(mgr.pin_mut().setLimits(0, 0, 0).is_err());
+        mgr.pin_mut().setLimits(1000, 1000, 0).unwrap();
+        let r = mgr.pin_mut().execute();
+        assert!(r.unwrap_err().to_string().contains("limit"));
+    }
+
+    #[test]
+    fn test_stages() {
+        let json = r#"{ "pipeline": [
+            "foobar.las",
+            { "type": "filters.range", "limits": "Classification[2:2]" },
+            "out.las"
+        ] }"#;
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut().readPipeline(json).unwrap();
+        let stages = mgr.stages();
+        assert_eq!(stages.len(), 3);
+        assert_eq!(stages[0].name, "readers.las");
+        assert_eq!(stages[1].name, "filters.range");
+        assert_eq!(stages[1].inputs, vec![0]);
+        assert!(stages[1]
+            .options
+            .iter()
+            .any(|o| o.name == "limits" && o.value == "Classification[2:2]"));
+        assert_eq!(stages[2].name, "writers.las");
+        assert_eq!(mgr.roots(), vec![0]);
+        assert_eq!(mgr.leaves(), vec![2]);
+        assert!(mgr.nonStreamableStages().is_empty());
+
+        mgr.pin_mut().setOption(0, "filename", "other.las").unwrap();
+        let stages = mgr.stages();
+        let filenames: Vec<_> = stages[0]
+            .options
+            .iter()
+            .filter(|o| o.name == "filename")
+            .map(|o| o.value.as_str())
+            .collect();
+        assert_eq!(filenames, vec!["other.las"]);
+        assert!(mgr.pin_mut().setOption(3, "filename", "x.las").is_err());
+    }
 }
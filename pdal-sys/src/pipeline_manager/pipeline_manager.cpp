@@ -18,10 +18,175 @@
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 #include "pdal-sys/src/pipeline_manager/pipeline_manager.hpp"
+#include "pdal-sys/src/pipeline_manager/mod.rs.h"
 
+#include <algorithm>
 #include <sstream>
 
 namespace pdal_sys {
+    ViewReader::ViewReader(pdal::PointViewPtr source) : m_source(source) {}
+
+    std::string ViewReader::getName() const {
+        return "readers.pdal_sys_view";
+    }
+
+    void ViewReader::addDimensions(pdal::PointLayoutPtr layout) {
+        auto source = m_source->layout();
+        m_dims.clear();
+        for (auto id : source->dims()) {
+            auto target = layout->registerOrAssignDim(source->dimName(id), source->dimType(id));
+            m_dims.emplace_back(id, target);
+        }
+    }
+
+    pdal::point_count_t ViewReader::read(pdal::PointViewPtr view, pdal::point_count_t count) {
+        auto source = m_source->layout();
+        std::vector<char> buf(source->pointSize());
+        pdal::point_count_t n = std::min<pdal::point_count_t>(count, m_source->size());
+        for (pdal::PointId idx = 0; idx < n; ++idx) {
+            for (auto& dims : m_dims) {
+                m_source->getRawField(dims.first, idx, buf.data());
+                view->setField(dims.second, source->dimType(dims.first), idx, buf.data());
+            }
+        }
+        return n;
+    }
+
+    ExecutionMonitor::ExecutionMonitor(std::size_t stageCount) :
+            m_cancelled(false),
+            m_points(new std::atomic<std::uint64_t>[stageCount]),
+            m_stageCount(stageCount),
+            m_logStream(&m_logBuffer) {
+        for (std::size_t i = 0; i < stageCount; ++i) {
+            m_points[i].store(0);
+        }
+    }
+
+    void ExecutionMonitor::cancel() const {
+        m_cancelled.store(true);
+    }
+
+    bool ExecutionMonitor::isCancelled() const {
+        return m_cancelled.load(std::memory_order_relaxed);
+    }
+
+    void ExecutionMonitor::addPoints(std::size_t stage, pdal::point_count_t count) const {
+        if (stage < m_stageCount) {
+            m_points[stage].fetch_add(count, std::memory_order_relaxed);
+        }
+    }
+
+    rust::Vec<std::uint64_t> ExecutionMonitor::stagePoints() const {
+        rust::Vec<std::uint64_t> result;
+        for (std::size_t i = 0; i < m_stageCount; ++i) {
+            result.push_back(m_points[i].load(std::memory_order_relaxed));
+        }
+        return result;
+    }
+
+    rust::Vec<rust::String> ExecutionMonitor::drainLog() const {
+        rust::Vec<rust::String> result;
+        std::istringstream lines(m_logBuffer.takeLines());
+        std::string line;
+        while (std::getline(lines, line)) {
+            result.push_back(rust::String(line));
+        }
+        return result;
+    }
+
+    std::ostream* ExecutionMonitor::logStream() {
+        return &m_logStream;
+    }
+
+    std::string ExecutionMonitor::LogBuffer::takeLines() {
+        std::lock_guard<std::mutex> lock(m_mutex);
+        auto end = m_text.rfind('\n');
+        if (end == std::string::npos) {
+            return std::string();
+        }
+        std::string lines = m_text.substr(0, end + 1);
+        m_text.erase(0, end + 1);
+        return lines;
+    }
+
+    int ExecutionMonitor::LogBuffer::overflow(int c) {
+        if (c != traits_type::eof()) {
+            std::lock_guard<std::mutex> lock(m_mutex);
+            m_text.push_back(static_cast<char>(c));
+        }
+        return c;
+    }
+
+    std::streamsize ExecutionMonitor::LogBuffer::xsputn(const char* s, std::streamsize n) {
+        std::lock_guard<std::mutex> lock(m_mutex);
+        m_text.append(s, static_cast<std::size_t>(n));
+        return n;
+    }
+
+    StageCounter::StageCounter(std::shared_ptr<ExecutionMonitor> monitor, std::size_t stage) :
+            m_monitor(monitor), m_stage(stage) {}
+
+    std::string StageCounter::getName() const {
+        return "filters.pdal_sys_counter";
+    }
+
+    pdal::point_count_t StageCounter::count() const {
+        return m_count;
+    }
+
+    bool StageCounter::processOne(pdal::PointRef&) {
+        record(1);
+        return true;
+    }
+
+    void StageCounter::filter(pdal::PointView& view) {
+        record(view.size());
+    }
+
+    void StageCounter::ready(pdal::PointTableRef) {
+        m_runCount = 0;
+    }
+
+    void StageCounter::record(pdal::point_count_t count) {
+        // A stage shared by several consumers may be executed once for each of them, so only
+        // count the points exceeding those of its previous executions.
+        m_runCount += count;
+        if (m_runCount <= m_count) {
+            return;
+        }
+        pdal::point_count_t added = m_runCount - m_count;
+        m_count = m_runCount;
+        if (m_monitor) {
+            m_monitor->addPoints(m_stage, added);
+            if (m_monitor->isCancelled()) {
+                throw pdal::pdal_error("Pipeline execution cancelled");
+            }
+        }
+    }
+
+    LimitedPointTable::LimitedPointTable(std::uint64_t maxPoints, std::uint64_t maxBytes,
+            std::shared_ptr<ExecutionMonitor> monitor) :
+            m_maxPoints(maxPoints), m_maxBytes(maxBytes), m_monitor(monitor) {}
+
+    void LimitedPointTable::finish() {
+        m_monitor.reset();
+    }
+
+    char* LimitedPointTable::getPoint(pdal::PointId idx) {
+        // Every point access during a standard mode execution goes through here, allowing stages
+        // to be interrupted part way through.
+        if (m_monitor && m_monitor->isCancelled()) {
+            throw pdal::pdal_error("Pipeline execution cancelled");
+        }
+        if (m_maxPoints && idx >= m_maxPoints) {
+            throw pdal::pdal_error("Point limit of " + std::to_string(m_maxPoints) + " exceeded");
+        }
+        if (m_maxBytes && (idx + 1) * layout()->pointSize() > m_maxBytes) {
+            throw pdal::pdal_error("Memory limit of " + std::to_string(m_maxBytes) + " bytes exceeded");
+        }
+        return pdal::PointTable::getPoint(idx);
+    }
+
     std::unique_ptr<PipelineManager> createPipelineManager() {
         return std::unique_ptr<PipelineManager>(new PipelineManager());
     }
@@ -37,21 +202,171 @@ namespace pdal_sys {
         m_impl->readPipeline(std::string(path));
     }
 
+    void PipelineManager::setOption(std::size_t stage, rust::Str name, rust::Str value) {
+        const auto& stages = m_impl->stages();
+        if (stage >= stages.size()) {
+            throw pdal::pdal_error("Stage index " + std::to_string(stage) + " out of range");
+        }
+        pdal::Options opts = stages[stage]->getOptions();
+        opts.replace(std::string(name), std::string(value));
+        stages[stage]->setOptions(opts);
+    }
+
+    void PipelineManager::setLimits(std::size_t streamChunkSize, std::uint64_t maxPoints, std::uint64_t maxBytes) {
+        if (streamChunkSize == 0) {
+            throw pdal::pdal_error("Stream chunk size must be greater than zero");
+        }
+        m_streamChunkSize = streamChunkSize;
+        m_maxPoints = maxPoints;
+        m_maxBytes = maxBytes;
+    }
+
+    void PipelineManager::addInput(const std::shared_ptr<pdal_sys::point_view::PointView>& view) {
+        // Once attached, inputs stop the parsed roots from being roots, so remember them.
+        if (m_inputs.empty()) {
+            m_roots = m_impl->roots();
+        }
+        if (m_roots.empty()) {
+            throw pdal::pdal_error("Pipeline has no stages to receive input");
+        }
+        m_inputs.push_back(std::unique_ptr<ViewReader>(new ViewReader(view)));
+        for (auto stage : m_roots) {
+            stage->setInput(*m_inputs.back());
+        }
+    }
+
     bool PipelineManager::pipelineStreamable() const {
-        return m_impl->pipelineStreamable();
+        // `ViewReader` inputs hold their points in memory and can't be streamed.
+        return m_inputs.empty() && m_impl->pipelineStreamable();
+    }
+
+    rust::Vec<std::size_t> PipelineManager::nonStreamableStages() const {
+        const auto& stages = m_impl->stages();
+        rust::Vec<std::size_t> result;
+        for (std::size_t i = 0; i < stages.size(); ++i) {
+            if (!dynamic_cast<pdal::Streamable*>(stages[i])) {
+                result.push_back(i);
+            }
+        }
+        return result;
     }
 
     std::size_t PipelineManager::execute() {
-        return m_impl->execute();
+        pdal::Stage& leaf = attachCounters();
+        try {
+            auto count = executeStandard(leaf);
+            detachCounters();
+            return count;
+        } catch (...) {
+            detachCounters();
+            throw;
+        }
     }
 
-    void PipelineManager::executeStreamed() {
-         m_impl->execute(pdal::ExecMode::PreferStream);
+    bool PipelineManager::executeStreamed() {
+        bool streamed = pipelineStreamable();
+        pdal::Stage& leaf = attachCounters();
+        try {
+            if (streamed) {
+                // As done by `pdal::PipelineManager::executeStream`.
+                m_impl->validateStageOptions();
+                pdal::FixedPointTable table(m_streamChunkSize);
+                leaf.prepare(table);
+                leaf.execute(table);
+            } else {
+                executeStandard(leaf);
+            }
+            detachCounters();
+            return streamed;
+        } catch (...) {
+            detachCounters();
+            throw;
+        }
+    }
+
+    std::size_t PipelineManager::executeStandard(pdal::Stage& stage) {
+        // As done by `pdal::PipelineManager::execute`.
+        m_impl->validateStageOptions();
+        m_table.reset(new LimitedPointTable(m_maxPoints, m_maxBytes, m_monitor));
+        stage.prepare(*m_table);
+        m_views = stage.execute(*m_table);
+        m_table->finish();
+
+        std::size_t count = 0;
+        for (const auto& view : m_views) {
+            count += view->size();
+        }
+        return count;
+    }
+
+    rust::Vec<std::int64_t> PipelineManager::stagePointCounts() const {
+        rust::Vec<std::int64_t> result;
+        for (std::size_t i = 0; i < m_impl->stages().size(); ++i) {
+            result.push_back(i < m_counters.size() ? static_cast<std::int64_t>(m_counters[i]->count()) : -1);
+        }
+        return result;
+    }
+
+    std::shared_ptr<ExecutionMonitor> PipelineManager::monitor() {
+        if (!m_monitor) {
+            m_monitor = std::make_shared<ExecutionMonitor>(m_impl->stages().size());
+        }
+        return m_monitor;
+    }
+
+    void PipelineManager::captureLog() {
+        pdal::LogPtr log = pdal::Log::makeLog("pdal", monitor()->logStream());
+        log->setLevel(pdal::LogLevel::Info);
+        for (auto stage : m_impl->stages()) {
+            stage->setLog(log);
+        }
+    }
+
+    // Route the output of every stage through a `StageCounter`, returning the counter of the leaf to
+    // execute in place of the leaf itself.
+    pdal::Stage& PipelineManager::attachCounters() {
+        // Once attached, every stage is a leaf of `m_impl`, so find the one to execute beforehand.
+        pdal::Stage* leaf = m_impl->getStage();
+        if (!leaf) {
+            throw pdal::pdal_error("Pipeline has no stages");
+        }
+
+        const auto& stages = m_impl->stages();
+        m_counters.clear();
+        for (std::size_t i = 0; i < stages.size(); ++i) {
+            m_counters.emplace_back(new StageCounter(m_monitor, i));
+            m_counters[i]->setInput(*stages[i]);
+        }
+        // A stage feeding several consumers gets one counter, so its points are counted once.
+        for (auto consumer : stages) {
+            for (auto& input : consumer->getInputs()) {
+                auto it = std::find(stages.begin(), stages.end(), input);
+                // Inputs added via `addInput` aren't part of the parsed pipeline.
+                if (it != stages.end()) {
+                    input = m_counters[it - stages.begin()].get();
+                }
+            }
+        }
+        return *m_counters[std::find(stages.begin(), stages.end(), leaf) - stages.begin()];
+    }
+
+    // Restore the original stage graph so the pipeline can be written or inspected.
+    void PipelineManager::detachCounters() {
+        const auto& stages = m_impl->stages();
+        for (auto consumer : stages) {
+            for (auto& input : consumer->getInputs()) {
+                for (std::size_t i = 0; i < m_counters.size(); ++i) {
+                    if (input == m_counters[i].get()) {
+                        input = stages[i];
+                    }
+                }
+            }
+        }
     }
 
     using pdal_sys::point_view_set::PointViewSet;
     const PointViewSet& PipelineManager::views() const {
-        return m_impl->views();
+        return m_views;
     }
 
     rust::String PipelineManager::metadata() const {
@@ -63,7 +378,8 @@ namespace pdal_sys {
 
     rust::String PipelineManager::schema() const {
         std::stringstream strm;
-        pdal::MetadataNode root = m_impl->pointTable().layout()->toMetadata().clone("schema");
+        pdal::PointTableRef table = m_table ? static_cast<pdal::BasePointTable&>(*m_table) : m_impl->pointTable();
+        pdal::MetadataNode root = table.layout()->toMetadata().clone("schema");
         pdal::Utils::toJSON(root, strm);
         return rust::String(strm.str());
     }
@@ -73,4 +389,47 @@ namespace pdal_sys {
         pdal::PipelineWriter::writePipeline(m_impl->getStage(), strm);
         return rust::String(strm.str());
     }
-}
\ No newline at end of file
+
+    rust::Vec<StageInfo> PipelineManager::stages() const {
+        const auto& stages = m_impl->stages();
+        rust::Vec<StageInfo> result;
+        for (auto stage : stages) {
+            StageInfo info;
+            info.name = rust::String(stage->getName());
+            info.tag = rust::String(stage->tag());
+            for (auto input : stage->getInputs()) {
+                auto it = std::find(stages.begin(), stages.end(), input);
+                // Inputs added via `addInput` aren't part of the parsed pipeline.
+                if (it != stages.end()) {
+                    info.inputs.push_back(static_cast<std::size_t>(it - stages.begin()));
+                }
+            }
+            for (const auto& opt : stage->getOptions().getOptions()) {
+                info.options.push_back(StageOption{rust::String(opt.getName()), rust::String(opt.getValue())});
+            }
+            result.push_back(std::move(info));
+        }
+        return result;
+    }
+
+    rust::Vec<std::size_t> PipelineManager::roots() const {
+        // `ViewReader` inputs aren't part of the parsed pipeline.
+        return indicesOf(m_inputs.empty() ? m_impl->roots() : m_roots);
+    }
+
+    rust::Vec<std::size_t> PipelineManager::leaves() const {
+        return indicesOf(m_impl->leaves());
+    }
+
+    rust::Vec<std::size_t> PipelineManager::indicesOf(const std::vector<pdal::Stage*>& subset) const {
+        const auto& stages = m_impl->stages();
+        rust::Vec<std::size_t> result;
+        for (auto stage : subset) {
+            auto it = std::find(stages.begin(), stages.end(), stage);
+            if (it != stages.end()) {
+                result.push_back(static_cast<std::size_t>(it - stages.begin()));
+            }
+        }
+        return result;
+    }
+}
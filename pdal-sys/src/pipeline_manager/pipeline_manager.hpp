@@ -20,25 +20,148 @@
 #pragma once
 #include "rust/cxx.h"
 #include <pdal/pdal.hpp>
+#include <pdal/Reader.hpp>
+#include <pdal/Filter.hpp>
+#include <pdal/Streamable.hpp>
 #include "pdal-sys/src/point_view/point_view.hpp"
 
+#include <atomic>
+#include <mutex>
+#include <ostream>
+
 namespace pdal_sys {
 
+struct StageInfo;
+
+/// Reader stage sourcing its points from a point view constructed outside the pipeline.
+class ViewReader : public pdal::Reader {
+public:
+    explicit ViewReader(pdal::PointViewPtr source);
+    std::string getName() const override;
+
+private:
+    void addDimensions(pdal::PointLayoutPtr layout) override;
+    pdal::point_count_t read(pdal::PointViewPtr view, pdal::point_count_t count) override;
+
+    pdal::PointViewPtr m_source;
+    std::vector<std::pair<pdal::Dimension::Id, pdal::Dimension::Id>> m_dims;
+};
+
+/// Thread-safe handle onto a running pipeline, for progress reporting, log capture and cancellation.
+class ExecutionMonitor {
+public:
+    explicit ExecutionMonitor(std::size_t stageCount);
+    void cancel() const;
+    bool isCancelled() const;
+    void addPoints(std::size_t stage, pdal::point_count_t count) const;
+    rust::Vec<std::uint64_t> stagePoints() const;
+    rust::Vec<rust::String> drainLog() const;
+    std::ostream* logStream();
+
+private:
+    class LogBuffer : public std::streambuf {
+    public:
+        std::string takeLines();
+
+    protected:
+        int overflow(int c) override;
+        std::streamsize xsputn(const char* s, std::streamsize n) override;
+
+    private:
+        std::mutex m_mutex;
+        std::string m_text;
+    };
+
+    mutable std::atomic<bool> m_cancelled;
+    std::unique_ptr<std::atomic<std::uint64_t>[]> m_points;
+    std::size_t m_stageCount;
+    mutable LogBuffer m_logBuffer;
+    std::ostream m_logStream;
+};
+
+/// Pass-through filter counting the points produced by its input stage.
+class StageCounter : public pdal::Filter, public pdal::Streamable {
+public:
+    StageCounter(std::shared_ptr<ExecutionMonitor> monitor, std::size_t stage);
+    std::string getName() const override;
+    pdal::point_count_t count() const;
+
+private:
+    bool processOne(pdal::PointRef& point) override;
+    void filter(pdal::PointView& view) override;
+    void ready(pdal::PointTableRef table) override;
+
+    void record(pdal::point_count_t count);
+
+    pdal::point_count_t m_count = 0;
+    // Points produced by the current execution of the input stage.
+    pdal::point_count_t m_runCount = 0;
+    // Null unless a monitor was requested before execution.
+    std::shared_ptr<ExecutionMonitor> m_monitor;
+    std::size_t m_stage;
+};
+
+/// Point table that fails once more than a maximum number of points or bytes is allocated, or
+/// once execution is cancelled.
+///
+/// Zero disables the respective limit.
+class LimitedPointTable : public pdal::PointTable {
+public:
+    LimitedPointTable(std::uint64_t maxPoints, std::uint64_t maxBytes, std::shared_ptr<ExecutionMonitor> monitor);
+    /// Stop checking for cancellation, once the points have been produced.
+    void finish();
+
+protected:
+    char* getPoint(pdal::PointId idx) override;
+
+private:
+    std::uint64_t m_maxPoints;
+    std::uint64_t m_maxBytes;
+    std::shared_ptr<ExecutionMonitor> m_monitor;
+};
+
 class PipelineManager {
 public:
     PipelineManager();
     void readPipeline(rust::Str json);
     void readPipelineFromFile(rust::Str path);
+    void setOption(std::size_t stage, rust::Str name, rust::Str value);
+    void setLimits(std::size_t streamChunkSize, std::uint64_t maxPoints, std::uint64_t maxBytes);
+    void addInput(const std::shared_ptr<pdal_sys::point_view::PointView>& view);
     bool pipelineStreamable() const;
+    rust::Vec<std::size_t> nonStreamableStages() const;
     std::size_t execute();
-    void executeStreamed();
+    bool executeStreamed();
+    rust::Vec<std::int64_t> stagePointCounts() const;
+    std::shared_ptr<ExecutionMonitor> monitor();
+    void captureLog();
     const pdal_sys::point_view_set::PointViewSet& views() const;
     rust::String metadata() const;
     rust::String schema() const;
     rust::String pipeline() const;
+    rust::Vec<StageInfo> stages() const;
+    rust::Vec<std::size_t> roots() const;
+    rust::Vec<std::size_t> leaves() const;
 
 private:
+    rust::Vec<std::size_t> indicesOf(const std::vector<pdal::Stage*>& stages) const;
+    std::size_t executeStandard(pdal::Stage& stage);
+    pdal::Stage& attachCounters();
+    void detachCounters();
+
     std::unique_ptr<pdal::PipelineManager> m_impl;
+    std::vector<std::unique_ptr<ViewReader>> m_inputs;
+    // Root stages of the parsed pipeline, recorded when the first input is added.
+    std::vector<pdal::Stage*> m_roots;
+    std::size_t m_streamChunkSize = 10000;
+    std::uint64_t m_maxPoints = 0;
+    std::uint64_t m_maxBytes = 0;
+    // Set by standard mode executions, replacing the manager's own table and views.
+    std::unique_ptr<LimitedPointTable> m_table;
+    pdal::PointViewSet m_views;
+    // Indexed as `m_impl->stages()`; empty until executed.
+    std::vector<std::unique_ptr<StageCounter>> m_counters;
+    std::shared_ptr<ExecutionMonitor> m_monitor;
 };
 
 std::unique_ptr<PipelineManager> createPipelineManager();
@@ -45,7 +45,7 @@ mod ffi {
         fn proj4(pv: &PointView) -> Result<String>;
         fn wkt(pv: &PointView) -> Result<String>;
         #[namespace = "pdal_sys::core"]
-        type DimTypeId = crate::core::DimTypeId;
+        type DimTypeId = crate::core::Dimension;
         fn pointField_i8(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<i8>;
         fn pointField_u8(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<u8>;
         fn pointField_i16(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<i16>;
@@ -56,20 +56,160 @@ mod ffi {
         fn pointField_u64(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<u64>;
         fn pointField_f32(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<f32>;
         fn pointField_f64(pv: &PointView, dim: DimTypeId, idx: u64) -> Result<f64>;
+        fn pointFields_i8(pv: &PointView, dim: DimTypeId, start: u64, out: &mut [i8])
+            -> Result<()>;
+        fn pointFields_u8(pv: &PointView, dim: DimTypeId, start: u64, out: &mut [u8])
+            -> Result<()>;
+        fn pointFields_i16(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [i16],
+        ) -> Result<()>;
+        fn pointFields_u16(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [u16],
+        ) -> Result<()>;
+        fn pointFields_i32(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [i32],
+        ) -> Result<()>;
+        fn pointFields_u32(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [u32],
+        ) -> Result<()>;
+        fn pointFields_i64(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [i64],
+        ) -> Result<()>;
+        fn pointFields_u64(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [u64],
+        ) -> Result<()>;
+        fn pointFields_f32(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [f32],
+        ) -> Result<()>;
+        fn pointFields_f64(
+            pv: &PointView,
+            dim: DimTypeId,
+            start: u64,
+            out: &mut [f64],
+        ) -> Result<()>;
+
+        fn makeNew(view: &SharedPtr<PointView>) -> SharedPtr<PointView>;
+        fn subset(view: &SharedPtr<PointView>, ids: &[u64]) -> Result<SharedPtr<PointView>>;
+        fn append(
+            view: &SharedPtr<PointView>,
+            other: &SharedPtr<PointView>,
+        ) -> Result<SharedPtr<PointView>>;
+        fn sortByDimension(
+            view: &SharedPtr<PointView>,
+            dim: DimTypeId,
+        ) -> Result<SharedPtr<PointView>>;
+
+        type PointViewBuilder;
+        #[namespace = "pdal_sys::core"]
+        type DimTypeEncoding = crate::core::DimTypeEncoding;
+        fn createPointViewBuilder() -> UniquePtr<PointViewBuilder>;
+        fn registerDim(
+            self: Pin<&mut PointViewBuilder>,
+            name: &str,
+            enc: DimTypeEncoding,
+        ) -> Result<DimTypeId>;
+        fn setFields_i8(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[i8],
+        ) -> Result<()>;
+        fn setFields_u8(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[u8],
+        ) -> Result<()>;
+        fn setFields_i16(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[i16],
+        ) -> Result<()>;
+        fn setFields_u16(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[u16],
+        ) -> Result<()>;
+        fn setFields_i32(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[i32],
+        ) -> Result<()>;
+        fn setFields_u32(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[u32],
+        ) -> Result<()>;
+        fn setFields_i64(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[i64],
+        ) -> Result<()>;
+        fn setFields_u64(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[u64],
+        ) -> Result<()>;
+        fn setFields_f32(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[f32],
+        ) -> Result<()>;
+        fn setFields_f64(
+            self: Pin<&mut PointViewBuilder>,
+            dim: DimTypeId,
+            values: &[f64],
+        ) -> Result<()>;
+        fn build(self: Pin<&mut PointViewBuilder>) -> Result<SharedPtr<PointView>>;
     }
 
     // This triggers the generation of the C++ template backing this concrete type.
     // See: https://cxx.rs/extern-c++.html#explicit-shim-trait-impls
     impl Vec<DimTypeId> {}
 }
-pub use ffi::{PointView, PointViewSet, PointViewSetIter};
+pub use ffi::{
+    append, createPointViewBuilder, makeNew, sortByDimension, subset, PointView, PointViewBuilder,
+    PointViewSet, PointViewSetIter,
+};
 
-use crate::core::{pdal_sys_throw, DimTypeEncoding, DimTypeId, PdalType, PdalValue, PointId};
+use crate::core::{pdal_sys_throw, DimTypeEncoding, Dimension, PdalType, PdalValue, PointId};
 use cxx::{SharedPtr, UniquePtr};
 use std::fmt::{Debug, Formatter};
+use std::mem;
+use std::pin::Pin;
 
 pub type PointViewPtr = SharedPtr<PointView>;
 
+// SAFETY: Views have no thread affinity, and are released through their `shared_ptr` control
+// block, whose reference count is atomic.
+unsafe impl Send for PointView {}
+// SAFETY: No operation available via `&PointView` modifies the view or its point table. Values are
+// read with `getFieldAs`, which only indexes the table's existing point blocks. `makeNew`,
+// `subset`, `append` and `sortByDimension` only fill the index of the new view they create, which
+// references existing points; in particular, sorting orders point IDs rather than swapping points
+// through temporaries added to the table. Points are only written via `PointViewBuilder`, before
+// the view is shared.
+unsafe impl Sync for PointView {}
+
 impl PointView {
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -90,9 +230,10 @@ impl PointView {
     }
     pub fn point_value_as<T: PdalType>(
         &self,
-        dim: DimTypeId,
+        dim: impl Into<Dimension>,
         idx: PointId,
     ) -> Result<T, cxx::Exception> {
+        let dim = dim.into();
         let r = match T::encoding() {
             DimTypeEncoding::Unsigned8 => T::static_cast(ffi::pointField_u8(self, dim, idx)?),
             DimTypeEncoding::Signed8 => T::static_cast(ffi::pointField_i8(self, dim, idx)?),
@@ -118,9 +259,88 @@ impl PointView {
         }
     }
 
+    /// Fetch the values of a dimension for all points in the view, converted to the specified
+    /// primitive type.
+    pub fn point_values_as<T: PdalType + Copy + Default>(
+        &self,
+        dim: impl Into<Dimension>,
+    ) -> Result<Vec<T>, cxx::Exception> {
+        let mut out = vec![T::default(); self.len() as usize];
+        self.read_values_as(dim, 0, &mut out)?;
+        Ok(out)
+    }
+
+    /// Fill `out` with the values of a dimension for consecutive points starting at `start`,
+    /// converted to the specified primitive type.
+    pub fn read_values_as<T: PdalType>(
+        &self,
+        dim: impl Into<Dimension>,
+        start: PointId,
+        out: &mut [T],
+    ) -> Result<(), cxx::Exception> {
+        let dim = dim.into();
+        // SAFETY: `T::encoding()` identifies the concrete primitive type of `T`.
+        unsafe {
+            match T::encoding() {
+                DimTypeEncoding::Unsigned8 => {
+                    ffi::pointFields_u8(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Signed8 => {
+                    ffi::pointFields_i8(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Unsigned16 => {
+                    ffi::pointFields_u16(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Signed16 => {
+                    ffi::pointFields_i16(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Unsigned32 => {
+                    ffi::pointFields_u32(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Signed32 => {
+                    ffi::pointFields_i32(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Unsigned64 => {
+                    ffi::pointFields_u64(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Signed64 => {
+                    ffi::pointFields_i64(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Float => {
+                    ffi::pointFields_f32(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::Double => {
+                    ffi::pointFields_f64(self, dim, start, slice_cast_mut(out))
+                }
+                DimTypeEncoding::None => Err(pdal_sys_throw(&format!(
+                    "Can't read values of dimension {dim} without a concrete type"
+                ))
+                .unwrap_err()),
+            }
+        }
+    }
+
     /// Get point dimension value as a discriminated union.
-    pub fn point_value(&self, dim: DimTypeId, idx: PointId) -> Result<PdalValue, cxx::Exception> {
-        match self.layout().dimEncoding(dim) {
+    pub fn point_value(
+        &self,
+        dim: impl Into<Dimension>,
+        idx: PointId,
+    ) -> Result<PdalValue, cxx::Exception> {
+        let dim = dim.into();
+        self.point_value_encoded(dim, self.layout().dimEncoding(dim), idx)
+    }
+
+    /// Get point dimension value as a discriminated union, given the dimension's encoding in
+    /// this view's layout.
+    ///
+    /// Avoids the layout lookup in [`PointView::point_value`] when the encoding is known.
+    pub fn point_value_encoded(
+        &self,
+        dim: Dimension,
+        encoding: DimTypeEncoding,
+        idx: PointId,
+    ) -> Result<PdalValue, cxx::Exception> {
+        match encoding {
             DimTypeEncoding::Unsigned8 => {
                 Ok(PdalValue::Unsigned8(ffi::pointField_u8(self, dim, idx)?))
             }
@@ -145,11 +365,10 @@ impl PointView {
             }
             DimTypeEncoding::Float => Ok(PdalValue::Float(ffi::pointField_f32(self, dim, idx)?)),
             DimTypeEncoding::Double => Ok(PdalValue::Double(ffi::pointField_f64(self, dim, idx)?)),
-            _ => Err(pdal_sys_throw(&format!(
-                "Failed to convert value to type {:?}",
-                self.layout().dimEncoding(dim)
-            ))
-            .unwrap_err()),
+            _ => Err(
+                pdal_sys_throw(&format!("Failed to convert value to type {encoding:?}"))
+                    .unwrap_err(),
+            ),
         }
     }
 }
@@ -163,6 +382,56 @@ impl Debug for PointView {
     }
 }
 
+pub type PointViewBuilderPtr = UniquePtr<PointViewBuilder>;
+
+impl PointViewBuilder {
+    pub fn new() -> PointViewBuilderPtr {
+        createPointViewBuilder()
+    }
+
+    /// Set the values of a registered dimension for all points in the view.
+    ///
+    /// The first call determines the number of points in the view; subsequent calls
+    /// must provide the same number of values.
+    pub fn set_fields<T: PdalType>(
+        self: Pin<&mut Self>,
+        dim: Dimension,
+        values: &[T],
+    ) -> Result<(), cxx::Exception> {
+        // SAFETY: `T::encoding()` identifies the concrete primitive type of `T`.
+        unsafe {
+            match T::encoding() {
+                DimTypeEncoding::Unsigned8 => self.setFields_u8(dim, slice_cast(values)),
+                DimTypeEncoding::Signed8 => self.setFields_i8(dim, slice_cast(values)),
+                DimTypeEncoding::Unsigned16 => self.setFields_u16(dim, slice_cast(values)),
+                DimTypeEncoding::Signed16 => self.setFields_i16(dim, slice_cast(values)),
+                DimTypeEncoding::Unsigned32 => self.setFields_u32(dim, slice_cast(values)),
+                DimTypeEncoding::Signed32 => self.setFields_i32(dim, slice_cast(values)),
+                DimTypeEncoding::Unsigned64 => self.setFields_u64(dim, slice_cast(values)),
+                DimTypeEncoding::Signed64 => self.setFields_i64(dim, slice_cast(values)),
+                DimTypeEncoding::Float => self.setFields_f32(dim, slice_cast(values)),
+                DimTypeEncoding::Double => self.setFields_f64(dim, slice_cast(values)),
+                DimTypeEncoding::None => Err(pdal_sys_throw(&format!(
+                    "Can't set values of dimension {dim} without a concrete type"
+                ))
+                .unwrap_err()),
+            }
+        }
+    }
+}
+
+/// Reinterpret a slice of one primitive type as another of identical representation.
+unsafe fn slice_cast<T, U>(values: &[T]) -> &[U] {
+    debug_assert_eq!(mem::size_of::<T>(), mem::size_of::<U>());
+    std::slice::from_raw_parts(values.as_ptr() as *const U, values.len())
+}
+
+/// Mutable counterpart to [`slice_cast`].
+unsafe fn slice_cast_mut<T, U>(values: &mut [T]) -> &mut [U] {
+    debug_assert_eq!(mem::size_of::<T>(), mem::size_of::<U>());
+    std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut U, values.len())
+}
+
 /// Unordered set of [`PointView`] instances.
 impl PointViewSet {
     #[inline]
@@ -190,7 +459,8 @@ impl Iterator for PointViewSetIterator<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::core::{DimTypeId, PdalValue, PointId};
+    use super::{append, createPointViewBuilder, makeNew, sortByDimension, subset};
+    use crate::core::{DimTypeEncoding, DimTypeId, PdalValue, PointId};
     use crate::pipeline_manager::createPipelineManager;
     use crate::testkit::*;
     use std::collections::HashMap;
@@ -273,5 +543,93 @@ mod tests {
                 .expect("x coord"),
             expected[&DimTypeId::X].to_f64()
         );
+
+        let intensities = view
+            .point_values_as::<u16>(DimTypeId::Intensity)
+            .expect("intensity values");
+        assert_eq!(intensities.len(), view.len() as usize);
+        assert_eq!(
+            PdalValue::Unsigned16(intensities[example as usize]),
+            expected[&DimTypeId::Intensity]
+        );
+
+        let mut window = [0u16; 3];
+        view.read_values_as(DimTypeId::Intensity, example - 1, &mut window)
+            .expect("intensity range");
+        assert_eq!(
+            &window,
+            &intensities[example as usize - 1..example as usize + 2]
+        );
+        let past_end = view.read_values_as(DimTypeId::Intensity, view.len() - 1, &mut window);
+        assert!(past_end.is_err());
+    }
+
+    #[test]
+    fn test_derived_views() {
+        let mut builder = createPointViewBuilder();
+        let x = builder
+            .pin_mut()
+            .registerDim("X", DimTypeEncoding::Double)
+            .unwrap();
+        builder
+            .pin_mut()
+            .setFields_f64(x, &[0.0, 1.0, 2.0, 3.0])
+            .unwrap();
+        let view = builder.pin_mut().build().unwrap();
+
+        let odd = subset(&view, &[1, 3]).unwrap();
+        assert_eq!(odd.point_values_as::<f64>(x).unwrap(), vec![1.0, 3.0]);
+        assert!(subset(&view, &[4]).is_err());
+
+        let empty = makeNew(&view);
+        assert!(empty.is_empty());
+        assert_ne!(empty.id(), view.id());
+
+        drop(view);
+        let both = append(&odd, &odd).unwrap();
+        assert_eq!(
+            both.point_values_as::<f64>(x).unwrap(),
+            vec![1.0, 3.0, 1.0, 3.0]
+        );
+
+        let sorted = sortByDimension(&both, x).unwrap();
+        assert_eq!(
+            sorted.point_values_as::<f64>(x).unwrap(),
+            vec![1.0, 1.0, 3.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_concurrent_access() {
+        std::env::set_current_dir(TEST_WD.to_path_buf()).unwrap();
+        let mut mgr = createPipelineManager();
+        mgr.pin_mut()
+            .readPipelineFromFile(&data_file_path("stats.json"))
+            .unwrap();
+        mgr.pin_mut().execute().unwrap();
+        let view = mgr.views().unwrap().iter().next().unwrap();
+        let expected = view.point_values_as::<f64>(DimTypeId::Z).unwrap();
+
+        // Read the view from several threads while another derives views on the same table.
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..3 {
+                        let zs = view.point_values_as::<f64>(DimTypeId::Z).unwrap();
+                        assert_eq!(zs, expected);
+                    }
+                });
+            }
+            scope.spawn(|| {
+                for _ in 0..3 {
+                    let sorted = sortByDimension(&view, DimTypeId::Z.into()).unwrap();
+                    let zs = sorted.point_values_as::<f64>(DimTypeId::Z).unwrap();
+                    assert!(zs.windows(2).all(|w| w[0] <= w[1]));
+                    let picked = subset(&view, &[5, 1]).unwrap();
+                    assert_eq!(append(&sorted, &picked).unwrap().len(), view.len() + 2);
+                    assert!(makeNew(&view).is_empty());
+                }
+            });
+        });
     }
 }
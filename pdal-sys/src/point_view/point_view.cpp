@@ -17,7 +17,9 @@
 // DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#include <algorithm>
 #include <memory>
+#include <numeric>
 #include "pdal-sys/src/point_view/point_view.hpp"
 
 namespace pdal_sys {
@@ -62,6 +64,68 @@ namespace pdal_sys {
             return *view.layout();
         }
 
+        // `PointView` is `Sync` on the Rust side, so functions taking a view mustn't modify it or
+        // its point table.
+
+        namespace {
+            // Derived views only reference the point table of the views they're created from, so
+            // the deleter keeps those alive.
+            std::shared_ptr<PointView> derived(pdal::PointViewPtr view, const std::shared_ptr<PointView>& source) {
+                PointView* raw = view.get();
+                return std::shared_ptr<PointView>(raw, [view, source](PointView*) {});
+            }
+        }
+
+        std::shared_ptr<PointView> makeNew(const std::shared_ptr<PointView>& view) {
+            return derived(view->makeNew(), view);
+        }
+
+        std::shared_ptr<PointView> subset(const std::shared_ptr<PointView>& view, rust::Slice<const std::uint64_t> ids) {
+            pdal::PointViewPtr out = view->makeNew();
+            for (auto id : ids) {
+                if (id >= view->size()) {
+                    throw pdal::pdal_error("Point " + std::to_string(id) + " is out of range for a view of " +
+                        std::to_string(view->size()) + " points");
+                }
+                out->appendPoint(*view, id);
+            }
+            return derived(out, view);
+        }
+
+        std::shared_ptr<PointView> append(const std::shared_ptr<PointView>& view, const std::shared_ptr<PointView>& other) {
+            // Each point table owns its layout, so this also rejects views of other tables.
+            if (view->layout() != other->layout()) {
+                throw pdal::pdal_error("Can't append points from a view with a different point layout");
+            }
+            pdal::PointViewPtr out = view->makeNew();
+            out->append(*view);
+            out->append(*other);
+            return derived(derived(out, view), other);
+        }
+
+        std::shared_ptr<PointView> sortByDimension(const std::shared_ptr<PointView>& view, core::DimTypeId dim) {
+            if (!view->layout()->hasDim(dim)) {
+                throw pdal::pdal_error("Dimension " + std::to_string((int) dim) + " is not in the point layout");
+            }
+            // Sorting the view itself, as `filters.sort` does, swaps points through temporaries
+            // added to the shared point table, so sort IDs and append the points in order instead.
+            std::vector<double> keys(view->size());
+            for (pdal::PointId id = 0; id < keys.size(); ++id) {
+                keys[id] = view->getFieldAs<double>(dim, id);
+            }
+            std::vector<pdal::PointId> ids(keys.size());
+            std::iota(ids.begin(), ids.end(), 0);
+            std::stable_sort(ids.begin(), ids.end(), [&keys](pdal::PointId a, pdal::PointId b) {
+                return keys[a] < keys[b];
+            });
+
+            pdal::PointViewPtr out = view->makeNew();
+            for (auto id : ids) {
+                out->appendPoint(*view, id);
+            }
+            return derived(out, view);
+        }
+
         size_t pointSizeForDims(const PointView& view, const rust::Vec<core::DimTypeId>& dims) {
             size_t retval = 0;
             for (auto dim : dims) {
@@ -100,6 +164,125 @@ namespace pdal_sys {
         double pointField_f64(const PointView& pv, core::DimTypeId dim, pdal::PointId id) {
             return pv.getFieldAs<double>(dim, id);
         }
+        template <typename T>
+        void pointFields(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<T> out) {
+            if (start > pv.size() || out.size() > pv.size() - start) {
+                throw pdal::pdal_error("Range of " + std::to_string(out.size()) + " points from " +
+                    std::to_string(start) + " exceeds the " + std::to_string(pv.size()) +
+                    " points available for dimension '" + pv.layout()->dimName(dim) + "'");
+            }
+            for (std::size_t i = 0; i < out.size(); ++i) {
+                out[i] = pv.getFieldAs<T>(dim, start + i);
+            }
+        }
+
+        void pointFields_i8(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::int8_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_u8(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::uint8_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_i16(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::int16_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_u16(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::uint16_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_i32(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::int32_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_u32(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::uint32_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_i64(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::int64_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_u64(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<std::uint64_t> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_f32(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<float> out) {
+            pointFields(pv, dim, start, out);
+        }
+        void pointFields_f64(const PointView& pv, core::DimTypeId dim, pdal::PointId start, rust::Slice<double> out) {
+            pointFields(pv, dim, start, out);
+        }
+
+        std::unique_ptr<PointViewBuilder> createPointViewBuilder() {
+            return std::make_unique<PointViewBuilder>();
+        }
+
+        PointViewBuilder::PointViewBuilder() : m_table(std::make_shared<pdal::PointTable>()) {}
+
+        core::DimTypeId PointViewBuilder::registerDim(rust::Str name, core::DimTypeEncoding enc) {
+            if (m_view) {
+                throw pdal::pdal_error("Dimensions must be registered before point values are set");
+            }
+            // Standard dimension names resolve to their built-in identifiers; anything else is
+            // registered as a proprietary dimension.
+            return m_table->layout()->registerOrAssignDim(std::string(name), enc);
+        }
+
+        PointView& PointViewBuilder::view() {
+            if (!m_view) {
+                m_table->finalize();
+                // The view only holds a reference to its table, so the deleter keeps the table alive.
+                auto table = m_table;
+                m_view = pdal::PointViewPtr(new PointView(*table), [table](PointView* pv) { delete pv; });
+            }
+            return *m_view;
+        }
+
+        template <typename T>
+        void PointViewBuilder::setFields(core::DimTypeId dim, rust::Slice<const T> values) {
+            PointView& pv = view();
+            if (!pv.layout()->hasDim(dim)) {
+                throw pdal::pdal_error("Dimension " + std::to_string((int) dim) + " has not been registered");
+            }
+            if (pv.size() != 0 && pv.size() != values.size()) {
+                throw pdal::pdal_error("Expected " + std::to_string(pv.size()) + " values for dimension '" +
+                    pv.layout()->dimName(dim) + "', got " + std::to_string(values.size()));
+            }
+            pdal::PointId idx = 0;
+            for (const T& v : values) {
+                // Setting the value at index `size()` appends a point to the view.
+                pv.setField(dim, idx++, v);
+            }
+        }
+
+        void PointViewBuilder::setFields_i8(core::DimTypeId dim, rust::Slice<const std::int8_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_u8(core::DimTypeId dim, rust::Slice<const std::uint8_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_i16(core::DimTypeId dim, rust::Slice<const std::int16_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_u16(core::DimTypeId dim, rust::Slice<const std::uint16_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_i32(core::DimTypeId dim, rust::Slice<const std::int32_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_u32(core::DimTypeId dim, rust::Slice<const std::uint32_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_i64(core::DimTypeId dim, rust::Slice<const std::int64_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_u64(core::DimTypeId dim, rust::Slice<const std::uint64_t> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_f32(core::DimTypeId dim, rust::Slice<const float> values) {
+            setFields(dim, values);
+        }
+        void PointViewBuilder::setFields_f64(core::DimTypeId dim, rust::Slice<const double> values) {
+            setFields(dim, values);
+        }
+
+        std::shared_ptr<PointView> PointViewBuilder::build() {
+            view();
+            return m_view;
+        }
     }
 }
-
@@ -43,6 +43,48 @@ namespace pdal_sys {
         std::uint64_t pointField_u64(const PointView&, core::DimTypeId, pdal::PointId);
         float pointField_f32(const PointView&, core::DimTypeId, pdal::PointId);
         double pointField_f64(const PointView&, core::DimTypeId, pdal::PointId);
+        void pointFields_i8(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::int8_t>);
+        void pointFields_u8(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::uint8_t>);
+        void pointFields_i16(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::int16_t>);
+        void pointFields_u16(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::uint16_t>);
+        void pointFields_i32(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::int32_t>);
+        void pointFields_u32(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::uint32_t>);
+        void pointFields_i64(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::int64_t>);
+        void pointFields_u64(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<std::uint64_t>);
+        void pointFields_f32(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<float>);
+        void pointFields_f64(const PointView&, core::DimTypeId, pdal::PointId, rust::Slice<double>);
+        std::shared_ptr<PointView> makeNew(const std::shared_ptr<PointView>& view);
+        std::shared_ptr<PointView> subset(const std::shared_ptr<PointView>& view, rust::Slice<const std::uint64_t> ids);
+        std::shared_ptr<PointView> append(const std::shared_ptr<PointView>& view, const std::shared_ptr<PointView>& other);
+        std::shared_ptr<PointView> sortByDimension(const std::shared_ptr<PointView>& view, core::DimTypeId dim);
+
+        /// Constructs a standalone point view, backed by its own point table, one dimension at a time.
+        class PointViewBuilder {
+        public:
+            PointViewBuilder();
+            core::DimTypeId registerDim(rust::Str name, core::DimTypeEncoding enc);
+            void setFields_i8(core::DimTypeId dim, rust::Slice<const std::int8_t> values);
+            void setFields_u8(core::DimTypeId dim, rust::Slice<const std::uint8_t> values);
+            void setFields_i16(core::DimTypeId dim, rust::Slice<const std::int16_t> values);
+            void setFields_u16(core::DimTypeId dim, rust::Slice<const std::uint16_t> values);
+            void setFields_i32(core::DimTypeId dim, rust::Slice<const std::int32_t> values);
+            void setFields_u32(core::DimTypeId dim, rust::Slice<const std::uint32_t> values);
+            void setFields_i64(core::DimTypeId dim, rust::Slice<const std::int64_t> values);
+            void setFields_u64(core::DimTypeId dim, rust::Slice<const std::uint64_t> values);
+            void setFields_f32(core::DimTypeId dim, rust::Slice<const float> values);
+            void setFields_f64(core::DimTypeId dim, rust::Slice<const double> values);
+            std::shared_ptr<PointView> build();
+
+        private:
+            template <typename T>
+            void setFields(core::DimTypeId dim, rust::Slice<const T> values);
+            PointView& view();
+
+            std::shared_ptr<pdal::PointTable> m_table;
+            pdal::PointViewPtr m_view;
+        };
+
+        std::unique_ptr<PointViewBuilder> createPointViewBuilder();
     }
 
     namespace point_view_set {
@@ -0,0 +1,94 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![allow(dead_code)]
+
+#[cxx::bridge(namespace = "pdal_sys")]
+mod ffi {
+    /// Header-level summary of a point cloud, as reported by `pdal::Stage::preview`.
+    #[namespace = "pdal_sys::reader"]
+    #[derive(Debug, Clone, Default)]
+    struct QuickInfo {
+        point_count: u64,
+        has_bounds: bool,
+        min_x: f64,
+        min_y: f64,
+        min_z: f64,
+        max_x: f64,
+        max_y: f64,
+        max_z: f64,
+        srs_wkt: String,
+        dimension_names: Vec<String>,
+        metadata: String,
+    }
+
+    #[namespace = "pdal_sys::reader"]
+    unsafe extern "C++" {
+        include!("pdal-sys/src/reader/reader.hpp");
+        type Reader;
+        fn createReader(driver: &str, filename: &str) -> Result<UniquePtr<Reader>>;
+        fn inferReaderDriver(filename: &str) -> String;
+        fn driver(self: &Reader) -> String;
+        fn preview(self: Pin<&mut Reader>) -> Result<QuickInfo>;
+    }
+}
+pub use ffi::{createReader, inferReaderDriver, QuickInfo, Reader};
+
+use cxx::UniquePtr;
+use std::fmt::{Debug, Formatter};
+
+pub type ReaderPtr = UniquePtr<Reader>;
+
+impl Reader {
+    /// Create a reader for the given file, inferring the driver from its extension if `driver`
+    /// is empty.
+    pub fn new(driver: &str, filename: &str) -> Result<ReaderPtr, cxx::Exception> {
+        createReader(driver, filename)
+    }
+}
+
+impl Debug for Reader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reader")
+            .field("driver", &self.driver())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::*;
+
+    #[test]
+    fn test_preview() {
+        let mut reader = Reader::new("", &data_file_path("autzen_trim.laz")).unwrap();
+        assert_eq!(reader.driver(), "readers.las");
+        let info = reader.pin_mut().preview().unwrap();
+        assert_eq!(info.point_count, 110000);
+        assert!(info.has_bounds);
+        assert!(info.dimension_names.iter().any(|n| n == "Intensity"));
+    }
+
+    #[test]
+    fn test_infer_driver() {
+        assert_eq!(inferReaderDriver("foo.copc.laz"), "readers.copc");
+        assert!(Reader::new("", "foo.unknown").is_err());
+    }
+}
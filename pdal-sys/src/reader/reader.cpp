@@ -0,0 +1,84 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#include "pdal-sys/src/reader/reader.hpp"
+#include "pdal-sys/src/reader/mod.rs.h"
+
+#include <sstream>
+
+namespace pdal_sys {
+    namespace reader {
+        std::unique_ptr<Reader> createReader(rust::Str driver, rust::Str filename) {
+            std::string drv(driver);
+            std::string fname(filename);
+            if (drv.empty()) {
+                drv = pdal::StageFactory::inferReaderDriver(fname);
+                if (drv.empty()) {
+                    throw pdal::pdal_error("Unable to infer reader driver for '" + fname + "'");
+                }
+            }
+            return std::unique_ptr<Reader>(new Reader(drv, fname));
+        }
+
+        rust::String inferReaderDriver(rust::Str filename) {
+            return rust::String(pdal::StageFactory::inferReaderDriver(std::string(filename)));
+        }
+
+        Reader::Reader(const std::string& driver, const std::string& filename) :
+                m_stage(m_factory.createStage(driver)) {
+            if (!m_stage) {
+                throw pdal::pdal_error("Unable to create reader '" + driver + "'");
+            }
+            pdal::Options opts;
+            opts.add("filename", filename);
+            m_stage->setOptions(opts);
+        }
+
+        rust::String Reader::driver() const {
+            return rust::String(m_stage->getName());
+        }
+
+        QuickInfo Reader::preview() {
+            pdal::QuickInfo qi = m_stage->preview();
+            if (!qi.valid()) {
+                throw pdal::pdal_error("Reader '" + m_stage->getName() + "' can't provide a preview");
+            }
+
+            QuickInfo info;
+            info.point_count = qi.m_pointCount;
+            info.has_bounds = !qi.m_bounds.empty();
+            info.min_x = qi.m_bounds.minx;
+            info.min_y = qi.m_bounds.miny;
+            info.min_z = qi.m_bounds.minz;
+            info.max_x = qi.m_bounds.maxx;
+            info.max_y = qi.m_bounds.maxy;
+            info.max_z = qi.m_bounds.maxz;
+            info.srs_wkt = rust::String(qi.m_srs.getWKT());
+            for (const auto& name : qi.m_dimNames) {
+                info.dimension_names.push_back(rust::String(name));
+            }
+            if (qi.m_metadata.valid()) {
+                std::stringstream strm;
+                pdal::Utils::toJSON(qi.m_metadata.clone("metadata"), strm);
+                info.metadata = rust::String(strm.str());
+            }
+            return info;
+        }
+    }
+}
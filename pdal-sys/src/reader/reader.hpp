@@ -0,0 +1,45 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#pragma once
+#include "rust/cxx.h"
+#include <pdal/pdal.hpp>
+#include <pdal/StageFactory.hpp>
+
+namespace pdal_sys {
+    namespace reader {
+        struct QuickInfo;
+
+        /// A reader stage, constructed independently of a pipeline.
+        class Reader {
+        public:
+            Reader(const std::string& driver, const std::string& filename);
+            rust::String driver() const;
+            QuickInfo preview();
+
+        private:
+            // The factory owns the stages it creates.
+            pdal::StageFactory m_factory;
+            pdal::Stage* m_stage;
+        };
+
+        std::unique_ptr<Reader> createReader(rust::Str driver, rust::Str filename);
+        rust::String inferReaderDriver(rust::Str filename);
+    }
+}
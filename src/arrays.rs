@@ -0,0 +1,218 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::error::Result;
+use crate::{DimTypeEncoding, Dimension, PdalType, PdalValue, PointView};
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+
+/// Values of a single dimension for every point in a view, typed by the dimension's encoding.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DimensionColumn {
+    Unsigned8(Vec<u8>),
+    Signed8(Vec<i8>),
+    Unsigned16(Vec<u16>),
+    Signed16(Vec<i16>),
+    Unsigned32(Vec<u32>),
+    Signed32(Vec<i32>),
+    Unsigned64(Vec<u64>),
+    Signed64(Vec<i64>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+}
+
+macro_rules! column_dispatch {
+    ($col:expr, $v:ident => $body:expr) => {
+        match $col {
+            DimensionColumn::Unsigned8($v) => $body,
+            DimensionColumn::Signed8($v) => $body,
+            DimensionColumn::Unsigned16($v) => $body,
+            DimensionColumn::Signed16($v) => $body,
+            DimensionColumn::Unsigned32($v) => $body,
+            DimensionColumn::Signed32($v) => $body,
+            DimensionColumn::Unsigned64($v) => $body,
+            DimensionColumn::Signed64($v) => $body,
+            DimensionColumn::Float($v) => $body,
+            DimensionColumn::Double($v) => $body,
+        }
+    };
+}
+
+impl DimensionColumn {
+    /// Read the values of `dim` from `view`, using the given encoding to select the column type.
+    fn read(view: &PointView, dim: Dimension, encoding: DimTypeEncoding) -> Result<Self> {
+        Ok(match encoding {
+            DimTypeEncoding::Unsigned8 => Self::Unsigned8(view.dimension_values(dim)?),
+            DimTypeEncoding::Signed8 => Self::Signed8(view.dimension_values(dim)?),
+            DimTypeEncoding::Unsigned16 => Self::Unsigned16(view.dimension_values(dim)?),
+            DimTypeEncoding::Signed16 => Self::Signed16(view.dimension_values(dim)?),
+            DimTypeEncoding::Unsigned32 => Self::Unsigned32(view.dimension_values(dim)?),
+            DimTypeEncoding::Signed32 => Self::Signed32(view.dimension_values(dim)?),
+            DimTypeEncoding::Unsigned64 => Self::Unsigned64(view.dimension_values(dim)?),
+            DimTypeEncoding::Signed64 => Self::Signed64(view.dimension_values(dim)?),
+            DimTypeEncoding::Float => Self::Float(view.dimension_values(dim)?),
+            DimTypeEncoding::Double => Self::Double(view.dimension_values(dim)?),
+            DimTypeEncoding::None => {
+                return Err(format!("Dimension '{dim}' has no concrete encoding").into())
+            }
+        })
+    }
+
+    /// The encoding of the values in this column.
+    pub fn encoding(&self) -> DimTypeEncoding {
+        match self {
+            DimensionColumn::Unsigned8(_) => DimTypeEncoding::Unsigned8,
+            DimensionColumn::Signed8(_) => DimTypeEncoding::Signed8,
+            DimensionColumn::Unsigned16(_) => DimTypeEncoding::Unsigned16,
+            DimensionColumn::Signed16(_) => DimTypeEncoding::Signed16,
+            DimensionColumn::Unsigned32(_) => DimTypeEncoding::Unsigned32,
+            DimensionColumn::Signed32(_) => DimTypeEncoding::Signed32,
+            DimensionColumn::Unsigned64(_) => DimTypeEncoding::Unsigned64,
+            DimensionColumn::Signed64(_) => DimTypeEncoding::Signed64,
+            DimensionColumn::Float(_) => DimTypeEncoding::Float,
+            DimensionColumn::Double(_) => DimTypeEncoding::Double,
+        }
+    }
+
+    /// Number of values in the column.
+    pub fn len(&self) -> usize {
+        column_dispatch!(self, v => v.len())
+    }
+
+    /// Determine if the column is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the value at the given index as a wrapped [`PdalValue`].
+    pub fn get(&self, idx: usize) -> Option<PdalValue> {
+        column_dispatch!(self, v => v.get(idx).map(|&x| x.into_pdal_value()))
+    }
+}
+
+/// Columnar copy of the points in a [`PointView`], keyed by dimension name.
+///
+/// This is the equivalent of the structured arrays returned by `pipeline.arrays` in PDAL's Python
+/// bindings. Columns are ordered as in the view's layout.
+#[derive(Clone, PartialEq)]
+pub struct PointArray {
+    len: usize,
+    columns: Vec<(String, DimensionColumn)>,
+}
+
+impl PointArray {
+    /// Copy all dimensions of the given view into columns.
+    pub fn from_view(view: &PointView) -> Result<Self> {
+        let layout = view.layout()?;
+        let columns = layout
+            .dimension_types()
+            .map(|dt| {
+                Ok((
+                    dt.name(),
+                    DimensionColumn::read(view, dt.id(), dt.encoding())?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { len: view.len(), columns })
+    }
+
+    /// Number of points in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determine if the array contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Names of the dimensions in the array.
+    pub fn dimension_names(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Iterator over `(name, column)` pairs.
+    pub fn columns(&self) -> impl Iterator<Item = (&str, &DimensionColumn)> {
+        self.columns.iter().map(|(name, col)| (name.as_str(), col))
+    }
+
+    /// Lookup a column by (case-insensitive) dimension name.
+    ///
+    /// Returns `None` if the array has no such dimension.
+    pub fn column(&self, name: &str) -> Option<&DimensionColumn> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, col)| col)
+    }
+}
+
+/// Serialized as a sequence of values.
+impl Serialize for DimensionColumn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        column_dispatch!(self, v => v.serialize(serializer))
+    }
+}
+
+/// Serialized as a map of dimension name to column.
+impl Serialize for PointArray {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_map(self.columns())
+    }
+}
+
+impl Debug for PointArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointArray")
+            .field("len", &self.len)
+            .field("dimensions", &self.dimension_names().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{read_test_file, TestResult};
+    use crate::{DimTypeEncoding, DimensionColumn, PdalValue, Pipeline};
+
+    #[test]
+    fn test_arrays() -> TestResult {
+        let json = read_test_file("stats.json");
+        let result = Pipeline::new(json)?.execute()?;
+        let arrays = result.arrays()?;
+        assert_eq!(arrays.len(), 1);
+
+        let array = &arrays[0];
+        assert_eq!(array.len(), 110000);
+        assert_eq!(array.dimension_names().count(), 20);
+
+        let intensity = array.column("Intensity").ok_or("no Intensity column")?;
+        assert_eq!(intensity.encoding(), DimTypeEncoding::Unsigned16);
+        assert_eq!(intensity.len(), array.len());
+        assert_eq!(intensity.get(4), Some(PdalValue::Unsigned16(15)));
+        assert!(array.column("intensity").is_some());
+
+        assert!(matches!(
+            array.column("X"),
+            Some(DimensionColumn::Double(_))
+        ));
+        assert!(array.column("NotADimension").is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversion from Apache Arrow record batches into PDAL point views.
+
+use crate::error::{Error, Result};
+use crate::{DimTypeEncoding, Dimension, PdalType, PointView};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, ArrowPrimitiveType, RecordBatch};
+use arrow_schema::DataType;
+use pdal_sys::point_view::PointViewBuilder;
+use std::pin::Pin;
+
+impl PointView {
+    /// Create a point view from an Arrow [`RecordBatch`].
+    ///
+    /// Each column is mapped to the standard PDAL dimension of the same (case-insensitive) name,
+    /// or otherwise registered as a proprietary dimension, and must be a non-null integer or
+    /// floating point array. The returned view is backed by its own point
+    /// table, and may be processed by filters and writers via [`Pipeline::add_input`](crate::Pipeline::add_input).
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self> {
+        let schema = batch.schema();
+        let mut builder = PointViewBuilder::new();
+        let mut dims: Vec<Dimension> = Vec::with_capacity(batch.num_columns());
+        for field in schema.fields() {
+            let enc = encoding(field.data_type()).ok_or_else(|| {
+                format!(
+                    "Unsupported data type {} for dimension '{}'",
+                    field.data_type(),
+                    field.name()
+                )
+            })?;
+            let dim = builder.pin_mut().registerDim(field.name(), enc)?;
+            if dims.contains(&dim) {
+                return Err(format!("Duplicate column for dimension '{}'", field.name()).into());
+            }
+            dims.push(dim);
+        }
+
+        for ((&dim, column), field) in dims.iter().zip(batch.columns()).zip(schema.fields()) {
+            if column.null_count() > 0 {
+                return Err(format!(
+                    "Column for dimension '{}' contains null values",
+                    field.name()
+                )
+                .into());
+            }
+            let b = builder.pin_mut();
+            match column.data_type() {
+                DataType::UInt8 => set_column::<UInt8Type>(b, dim, column)?,
+                DataType::Int8 => set_column::<Int8Type>(b, dim, column)?,
+                DataType::UInt16 => set_column::<UInt16Type>(b, dim, column)?,
+                DataType::Int16 => set_column::<Int16Type>(b, dim, column)?,
+                DataType::UInt32 => set_column::<UInt32Type>(b, dim, column)?,
+                DataType::Int32 => set_column::<Int32Type>(b, dim, column)?,
+                DataType::UInt64 => set_column::<UInt64Type>(b, dim, column)?,
+                DataType::Int64 => set_column::<Int64Type>(b, dim, column)?,
+                DataType::Float32 => set_column::<Float32Type>(b, dim, column)?,
+                DataType::Float64 => set_column::<Float64Type>(b, dim, column)?,
+                _ => unreachable!("column types checked above"),
+            }
+        }
+
+        Ok(PointView(builder.pin_mut().build()?))
+    }
+}
+
+impl TryFrom<&RecordBatch> for PointView {
+    type Error = Error;
+
+    fn try_from(batch: &RecordBatch) -> Result<Self> {
+        PointView::from_record_batch(batch)
+    }
+}
+
+/// Get the PDAL encoding corresponding to an Arrow data type, if supported.
+fn encoding(data_type: &DataType) -> Option<DimTypeEncoding> {
+    Some(match data_type {
+        DataType::UInt8 => DimTypeEncoding::Unsigned8,
+        DataType::Int8 => DimTypeEncoding::Signed8,
+        DataType::UInt16 => DimTypeEncoding::Unsigned16,
+        DataType::Int16 => DimTypeEncoding::Signed16,
+        DataType::UInt32 => DimTypeEncoding::Unsigned32,
+        DataType::Int32 => DimTypeEncoding::Signed32,
+        DataType::UInt64 => DimTypeEncoding::Unsigned64,
+        DataType::Int64 => DimTypeEncoding::Signed64,
+        DataType::Float32 => DimTypeEncoding::Float,
+        DataType::Float64 => DimTypeEncoding::Double,
+        _ => return None,
+    })
+}
+
+fn set_column<T>(
+    builder: Pin<&mut PointViewBuilder>,
+    dim: Dimension,
+    column: &dyn Array,
+) -> Result<()>
+where
+    T: ArrowPrimitiveType,
+    T::Native: PdalType,
+{
+    builder.set_fields(dim, column.as_primitive::<T>().values())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{TestResult, TARGET_DIR};
+    use crate::{DimTypeEncoding, DimTypeId, PdalValue, Pipeline, PointView};
+    use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray, UInt16Array};
+    use std::sync::Arc;
+
+    fn fixture() -> RecordBatch {
+        RecordBatch::try_from_iter([
+            (
+                "X",
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])) as ArrayRef,
+            ),
+            (
+                "Y",
+                Arc::new(Float64Array::from(vec![4.0, 5.0, 6.0])) as ArrayRef,
+            ),
+            (
+                "Z",
+                Arc::new(Float64Array::from(vec![7.0, 8.0, 9.0])) as ArrayRef,
+            ),
+            (
+                "Intensity",
+                Arc::new(UInt16Array::from(vec![10, 20, 30])) as ArrayRef,
+            ),
+        ])
+        .expect("valid record batch")
+    }
+
+    #[test]
+    fn test_from_record_batch() -> TestResult {
+        let view = PointView::from_record_batch(&fixture())?;
+        assert_eq!(view.len(), 3);
+        assert_eq!(
+            view.point_value(DimTypeId::Intensity, 1)?,
+            PdalValue::Unsigned16(20)
+        );
+        assert_eq!(view.point_value_as::<f64>(DimTypeId::Z, 2)?, 9.0);
+
+        let layout = view.layout()?;
+        assert_eq!(layout.dimension_count(), 4);
+        let dim = layout
+            .dimension_type(DimTypeId::Intensity)
+            .ok_or("Intensity dimension not found")?;
+        assert_eq!(dim.encoding(), DimTypeEncoding::Unsigned16);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_dimension() -> TestResult {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "X",
+                Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+            ),
+            (
+                "TreeId",
+                Arc::new(UInt16Array::from(vec![7, 9])) as ArrayRef,
+            ),
+        ])?;
+        let view = PointView::from_record_batch(&batch)?;
+        let layout = view.layout()?;
+        let tree_id = layout
+            .find_dimension("TreeId")
+            .ok_or("TreeId dimension not found")?;
+        assert!(!tree_id.is_standard());
+        assert_eq!(layout.dimension_ids().count(), 2);
+        assert_eq!(view.point_value(tree_id, 1)?, PdalValue::Unsigned16(9));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_record_batch() {
+        let batch = RecordBatch::try_from_iter([(
+            "Classification",
+            Arc::new(StringArray::from(vec!["ground"])) as ArrayRef,
+        )])
+        .unwrap();
+        assert!(PointView::from_record_batch(&batch).is_err());
+    }
+
+    #[test]
+    fn test_write_record_batch() -> TestResult {
+        let view = PointView::try_from(&fixture())?;
+        let filename = TARGET_DIR.join("from_arrow.las");
+        let json = serde_json::json!([{ "type": "writers.las", "filename": filename }]);
+        let mut pipeline = Pipeline::new(json.to_string())?;
+        pipeline.add_input(&view)?;
+        pipeline.add_input(&view)?;
+        assert_eq!(pipeline.roots().len(), 1);
+        let result = pipeline.execute()?;
+        assert_eq!(result.point_count(), 6);
+        Ok(())
+    }
+}
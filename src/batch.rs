@@ -0,0 +1,208 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::error::Result;
+use crate::{ExecLimits, ExecMode, PipelineTemplate};
+use serde_json::Value;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Runs a [`PipelineTemplate`] over many input files in parallel.
+///
+/// Each file is bound to the template's `${input}` placeholder, its file stem to `${stem}` and
+/// its position in the batch to `${index}`, if present. Every file gets its own pipeline, and a
+/// failure doesn't affect the rest of the batch.
+///
+/// ```no_run
+/// use pdal::{BatchRunner, PipelineTemplate};
+/// let template = PipelineTemplate::new(r#"["${input}", "out/${stem}.copc.laz"]"#)?;
+/// let results = BatchRunner::new(template)?.threads(4).run(["a.las", "b.las"]);
+/// for result in results {
+///     match result.outcome {
+///         Ok(output) => println!("{}: {} points", result.input.display(), output.point_count),
+///         Err(err) => eprintln!("{}: {err}", result.input.display()),
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchRunner {
+    template: PipelineTemplate,
+    params: Vec<(String, Value)>,
+    threads: usize,
+    mode: ExecMode,
+    limits: ExecLimits,
+}
+
+/// Outcome of running a [`BatchRunner`] on one input file.
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    pub input: PathBuf,
+    pub outcome: std::result::Result<BatchOutput, String>,
+}
+
+/// Summary of a successful pipeline execution within a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOutput {
+    pub point_count: usize,
+    pub streamed: bool,
+    /// Pipeline metadata as JSON.
+    pub metadata: String,
+}
+
+impl BatchRunner {
+    /// Create a runner for a template containing an `${input}` placeholder.
+    ///
+    /// Uses one thread per available CPU by default.
+    pub fn new(template: PipelineTemplate) -> Result<Self> {
+        if !template.placeholders().any(|p| p == "input") {
+            return Err("Batch template must contain an '${input}' placeholder".into());
+        }
+        let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        Ok(Self {
+            template,
+            params: Vec::new(),
+            threads,
+            mode: ExecMode::Standard,
+            limits: ExecLimits::default(),
+        })
+    }
+
+    /// Bind a template parameter shared by every file in the batch.
+    pub fn param<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the number of worker threads.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Set the execution mode for each pipeline.
+    pub fn mode(mut self, mode: ExecMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the resource limits for each pipeline.
+    pub fn limits(mut self, limits: ExecLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Run the pipeline for each input, returning results in input order.
+    pub fn run<I, P>(&self, inputs: I) -> Vec<BatchResult>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let inputs: Vec<PathBuf> = inputs.into_iter().map(|p| p.as_ref().into()).collect();
+        let next = AtomicUsize::new(0);
+
+        let mut results: Vec<(usize, BatchResult)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads.min(inputs.len()))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let idx = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(input) = inputs.get(idx) else {
+                                break;
+                            };
+                            let outcome = self.run_one(idx, input).map_err(|e| e.to_string());
+                            done.push((idx, BatchResult { input: input.clone(), outcome }));
+                        }
+                        done
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|w| w.join().expect("batch worker panicked"))
+                .collect()
+        });
+
+        results.sort_by_key(|(idx, _)| *idx);
+        results.into_iter().map(|(_, r)| r).collect()
+    }
+
+    fn run_one(&self, index: usize, input: &Path) -> Result<BatchOutput> {
+        let mut params = self.params.clone();
+        params.push(("input".into(), input.to_string_lossy().into()));
+        if self.template.placeholders().any(|p| p == "stem") {
+            let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+            params.push(("stem".into(), stem.into()));
+        }
+        if self.template.placeholders().any(|p| p == "index") {
+            params.push(("index".into(), index.into()));
+        }
+
+        let mut pipeline = self.template.render(params)?;
+        pipeline.set_limits(self.limits)?;
+        let result = pipeline.execute_with(self.mode)?;
+        Ok(BatchOutput {
+            point_count: result.point_count(),
+            streamed: result.is_streamed(),
+            metadata: result.metadata()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::{TestResult, DATA_DIR, TARGET_DIR};
+
+    #[test]
+    fn test_batch() -> TestResult {
+        // Inputs may share a stem, so outputs are also distinguished by index.
+        let out_path = TARGET_DIR.join("${stem}_batch_${index}.las");
+        let template = PipelineTemplate::new(format!(
+            r#"["${{input}}", {{"type": "filters.stats", "dimensions": "${{dims}}"}}, {}]"#,
+            Value::from(out_path.to_string_lossy())
+        ))?;
+        let input = DATA_DIR.join("autzen_trim.laz");
+        let results = BatchRunner::new(template)?
+            .param("dims", "Intensity")
+            .threads(2)
+            .mode(ExecMode::PreferStream)
+            .run([input.clone(), DATA_DIR.join("missing.laz"), input.clone()]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].input, input);
+        let output = results[0].outcome.clone()?;
+        assert_eq!(output.point_count, 110000);
+        assert!(output.metadata.contains("filters.stats"));
+        assert!(results[1].outcome.is_err());
+        assert_eq!(results[2].outcome.clone()?.point_count, 110000);
+        assert!(TARGET_DIR.join("autzen_trim_batch_0.las").exists());
+        assert!(TARGET_DIR.join("autzen_trim_batch_2.las").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_input_placeholder() -> TestResult {
+        let template = PipelineTemplate::new(r#"["a.las"]"#)?;
+        assert!(BatchRunner::new(template).is_err());
+        Ok(())
+    }
+}
@@ -29,6 +29,8 @@ pub struct Config {
     pub patch: i32,
     pub sha: String,
     pub plugin_path: PathBuf,
+    /// Version of PDAL the bindings were compiled against.
+    pub build_version: String,
 }
 
 impl Config {
@@ -40,6 +42,7 @@ impl Config {
             patch: pdal_sys::config::version_patch(),
             sha: pdal_sys::config::sha1(),
             plugin_path: pdal_sys::config::plugin_install_path().into(),
+            build_version: pdal_sys::config::BUILD_VERSION.to_string(),
         })
     }
 }
@@ -51,5 +54,8 @@ mod test {
     fn test_pdal_config() {
         let conf = Config::new().expect("PDAL Config");
         assert_eq!(conf.major, 2);
+        assert!(conf
+            .build_version
+            .starts_with(&format!("{}.{}", conf.major, conf.minor)));
     }
 }
@@ -20,20 +20,30 @@
 /// Dimension type identifier. E.g. X, Y, Z, Red, Green, Blue, etc.
 pub type DimTypeId = pdal_sys::core::DimTypeId;
 
+/// Identifier of a standard or proprietary dimension, as registered in a [`PointLayout`](crate::PointLayout).
+///
+/// Obtained from a [`DimTypeId`] via `From`, or by name via [`PointLayout::find_dimension`](crate::PointLayout::find_dimension).
+pub type Dimension = pdal_sys::core::Dimension;
+
 /// How the dimension is encoded. E.g. `Unsiged8`, `Signed64`, `Double`, etc.
 pub type DimTypeEncoding = pdal_sys::core::DimTypeEncoding;
 
 /// A dimension in a point layout.
 pub struct LayoutDimension<'view>(
     pub(crate) &'view crate::PointLayout<'view>,
-    pub(crate) DimTypeId,
+    pub(crate) Dimension,
 );
 
 impl<'view> LayoutDimension<'view> {
-    pub fn id(&self) -> DimTypeId {
+    pub fn id(&self) -> Dimension {
         self.1
     }
 
+    /// Name of the dimension, including for proprietary dimensions.
+    pub fn name(&self) -> String {
+        self.0.dimension_name(self.1)
+    }
+
     /// Size of the dimension in bytes.
     pub fn size_bytes(&self) -> usize {
         self.0.dimension_size(self.1)
@@ -0,0 +1,129 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Spatial queries over point views using PDAL's k-d tree indexes.
+
+use crate::error::Result;
+use crate::{PointId, PointView};
+use pdal_sys::kd_index::{createKD2Index, createKD3Index, KD2IndexPtr, KD3IndexPtr};
+use std::fmt::{Debug, Formatter};
+
+/// 2D spatial index over the X/Y coordinates of a [`PointView`].
+///
+/// See [`PointView::kd2_index`].
+pub struct Kd2Index(KD2IndexPtr);
+
+impl Kd2Index {
+    /// IDs of the points within distance `r` of `(x, y)`.
+    pub fn radius(&self, x: f64, y: f64, r: f64) -> Result<Vec<PointId>> {
+        Ok(self.0.radius(x, y, r)?)
+    }
+
+    /// IDs of the `k` points nearest to the point `point` in the view, ordered by distance.
+    /// The point itself is included.
+    pub fn neighbors(&self, point: PointId, k: usize) -> Result<Vec<PointId>> {
+        Ok(self.0.neighbors(point, k as u64)?)
+    }
+
+    /// IDs of the `k` points nearest to `(x, y)`, ordered by distance.
+    pub fn knn(&self, x: f64, y: f64, k: usize) -> Result<Vec<PointId>> {
+        Ok(self.0.knn(x, y, k as u64)?)
+    }
+}
+
+impl Debug for Kd2Index {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Kd2Index").finish_non_exhaustive()
+    }
+}
+
+/// 3D spatial index over the X/Y/Z coordinates of a [`PointView`].
+///
+/// See [`PointView::kd3_index`].
+pub struct Kd3Index(KD3IndexPtr);
+
+impl Kd3Index {
+    /// IDs of the points within distance `r` of `(x, y, z)`.
+    pub fn radius(&self, x: f64, y: f64, z: f64, r: f64) -> Result<Vec<PointId>> {
+        Ok(self.0.radius(x, y, z, r)?)
+    }
+
+    /// IDs of the `k` points nearest to the point `point` in the view, ordered by distance.
+    /// The point itself is included.
+    pub fn neighbors(&self, point: PointId, k: usize) -> Result<Vec<PointId>> {
+        Ok(self.0.neighbors(point, k as u64)?)
+    }
+
+    /// IDs of the `k` points nearest to `(x, y, z)`, ordered by distance.
+    pub fn knn(&self, x: f64, y: f64, z: f64, k: usize) -> Result<Vec<PointId>> {
+        Ok(self.0.knn(x, y, z, k as u64)?)
+    }
+}
+
+impl Debug for Kd3Index {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Kd3Index").finish_non_exhaustive()
+    }
+}
+
+impl PointView {
+    /// Build a 2D spatial index over the X/Y coordinates of the points in the view.
+    ///
+    /// The index keeps the underlying view alive.
+    pub fn kd2_index(&self) -> Result<Kd2Index> {
+        Ok(Kd2Index(createKD2Index(&self.0)?))
+    }
+
+    /// Build a 3D spatial index over the X/Y/Z coordinates of the points in the view.
+    ///
+    /// The index keeps the underlying view alive.
+    pub fn kd3_index(&self) -> Result<Kd3Index> {
+        Ok(Kd3Index(createKD3Index(&self.0)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{read_test_file, TestResult};
+    use crate::{DimTypeId, Pipeline};
+
+    #[test]
+    fn test_kd_index() -> TestResult {
+        let result = Pipeline::new(read_test_file("copy.json"))?.execute()?;
+        let view = result.point_views()?.pop().ok_or("no point view")?;
+        let x = view.point_value_as::<f64>(DimTypeId::X, 10)?;
+        let y = view.point_value_as::<f64>(DimTypeId::Y, 10)?;
+        let z = view.point_value_as::<f64>(DimTypeId::Z, 10)?;
+
+        let kd2 = view.kd2_index()?;
+        let near = kd2.neighbors(10, 8)?;
+        assert_eq!(near.len(), 8);
+        assert!(kd2.radius(x, y, 0.0001)?.contains(&10));
+        assert_eq!(kd2.knn(x, y, 8)?.len(), 8);
+
+        let kd3 = view.kd3_index()?;
+        assert_eq!(kd3.neighbors(10, 1)?, vec![10]);
+        assert!(kd3.radius(x, y, z, 0.0001)?.contains(&10));
+        let nearest = kd3.knn(x, y, z, 5)?;
+        assert_eq!(nearest.len(), 5);
+        assert!(kd3.radius(x, y, z, 0.0001)?.contains(&nearest[0]));
+        assert!(kd3.neighbors(view.len() as u64, 1).is_err());
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@
 // DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use crate::{DimTypeId, LayoutDimension};
+use crate::{Dimension, LayoutDimension};
 use std::fmt::Debug;
 
 /// Point layout definition, describing the schema the points in a view.
@@ -33,8 +33,9 @@ impl<'a> PointLayout<'a> {
         self.0.dim_ids().count()
     }
 
-    /// Returns the sequence of dimension identifiers used by the layout.
-    pub fn dimension_ids(&self) -> impl Iterator<Item = DimTypeId> {
+    /// Returns the sequence of dimension identifiers used by the layout, including proprietary
+    /// dimensions.
+    pub fn dimension_ids(&self) -> impl Iterator<Item = Dimension> {
         self.0.dim_ids()
     }
 
@@ -46,18 +47,31 @@ impl<'a> PointLayout<'a> {
     /// Lookup the dimension type by identifier.
     ///
     /// Returns `None` if identifier is not found.
-    pub fn dimension_type(&self, id: DimTypeId) -> Option<LayoutDimension> {
+    pub fn dimension_type(&self, id: impl Into<Dimension>) -> Option<LayoutDimension> {
+        let id = id.into();
         self.dimension_types().find(|dt| dt.id() == id)
     }
 
+    /// Lookup a dimension by name, including proprietary dimensions such as LAS extra bytes.
+    ///
+    /// Returns `None` if the layout has no dimension with the given name.
+    pub fn find_dimension(&self, name: &str) -> Option<Dimension> {
+        self.0.find_dim(name)
+    }
+
+    /// Get the name of the given dimension in this layout.
+    pub fn dimension_name(&self, id: impl Into<Dimension>) -> String {
+        self.0.dim_name(id.into())
+    }
+
     /// Get the size in bytes of the given dimension in this layout.
-    pub fn dimension_size(&self, id: DimTypeId) -> usize {
-        self.0.dimSize(id)
+    pub fn dimension_size(&self, id: impl Into<Dimension>) -> usize {
+        self.0.dimSize(id.into())
     }
 
     /// Returns the byte offset of a dimension type with the given name.
-    pub fn dimension_offset(&self, id: DimTypeId) -> usize {
-        self.0.dimOffset(id)
+    pub fn dimension_offset(&self, id: impl Into<Dimension>) -> usize {
+        self.0.dimOffset(id.into())
     }
 }
 
@@ -90,6 +104,13 @@ mod tests {
         assert_eq!(layout.dimension_size(DimTypeId::X), 8);
         assert_eq!(layout.dimension_offset(DimTypeId::Y), 1);
 
+        let intensity = layout
+            .find_dimension("intensity")
+            .ok_or("Intensity dimension not found")?;
+        assert_eq!(intensity, DimTypeId::Intensity);
+        assert_eq!(layout.dimension_name(intensity), "Intensity");
+        assert!(layout.find_dimension("NotADimension").is_none());
+
         Ok(())
     }
 }
@@ -18,21 +18,51 @@
 // OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 #![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod arrays;
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+mod arrow;
+mod batch;
 mod config;
 mod dimension;
 mod error;
+mod kd_index;
 mod layout;
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+mod ndarray;
+mod ordering;
 mod pipeline;
+mod point;
 mod point_view;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+mod rayon;
+mod reader;
+mod template;
 #[cfg(test)]
 mod testkit;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+mod tokio;
 pub(crate) mod utils;
 
+#[cfg(feature = "rayon")]
+pub use self::rayon::PointChunk;
+#[cfg(feature = "tokio")]
+pub use self::tokio::ExecEvent;
+pub use arrays::*;
+pub use batch::*;
 pub use config::*;
 pub use dimension::*;
+pub use kd_index::{Kd2Index, Kd3Index};
 pub use layout::*;
 pub use pipeline::*;
+pub use point::{PointRef, Points};
 pub use point_view::*;
+pub use reader::*;
+pub use template::*;
 
 // TODO: Log levels
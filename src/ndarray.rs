@@ -0,0 +1,72 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversion of point view dimensions into [`ndarray`] arrays.
+
+use crate::error::Result;
+use crate::{DimTypeId, Dimension, PdalType, PointView};
+use ndarray::{Array1, Array2};
+
+impl PointView {
+    /// Get the X, Y and Z coordinates of all points in the view as an `N×3` matrix.
+    pub fn xyz(&self) -> Result<Array2<f64>> {
+        let mut xyz = Array2::zeros((self.len(), 3));
+        for (i, dim) in [DimTypeId::X, DimTypeId::Y, DimTypeId::Z]
+            .into_iter()
+            .enumerate()
+        {
+            let values = self.dimension_values::<f64>(dim)?;
+            xyz.column_mut(i).assign(&Array1::from_vec(values));
+        }
+        Ok(xyz)
+    }
+
+    /// Get the values of a dimension for all points in the view as a one-dimensional array,
+    /// converted to the specified primitive type.
+    pub fn dimension_array<T: PdalType + Copy + Default>(
+        &self,
+        dim: impl Into<Dimension>,
+    ) -> Result<Array1<T>> {
+        Ok(Array1::from_vec(self.dimension_values(dim)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{read_test_file, TestResult};
+    use crate::{DimTypeId, Pipeline};
+
+    #[test]
+    fn test_arrays() -> TestResult {
+        let json = read_test_file("copy.json");
+        let result = Pipeline::new(json)?.execute()?;
+        let views = result.point_views()?;
+        let view = views.first().ok_or("no point view")?;
+
+        let xyz = view.xyz()?;
+        assert_eq!(xyz.dim(), (view.len(), 3));
+        assert_eq!(xyz[[4, 0]], view.point_value_as::<f64>(DimTypeId::X, 4)?);
+        assert_eq!(xyz[[4, 2]], view.point_value_as::<f64>(DimTypeId::Z, 4)?);
+
+        let classes = view.dimension_array::<u8>(DimTypeId::Classification)?;
+        assert_eq!(classes.len(), view.len());
+        assert_eq!(classes[4], 1);
+        Ok(())
+    }
+}
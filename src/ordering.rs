@@ -0,0 +1,197 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Sorting and spatial ordering of point views.
+
+use crate::error::Result;
+use crate::{DimTypeId, Dimension, PointId, PointRef, PointView};
+use std::cmp::Ordering;
+
+impl PointView {
+    /// Create a view of the points sorted by the values of `dim`, sharing this view's point
+    /// table. The sort is stable.
+    pub fn sort_by_dimension(&self, dim: impl Into<Dimension>) -> Result<PointView> {
+        Ok(PointView(pdal_sys::point_view::sortByDimension(
+            &self.0,
+            dim.into(),
+        )?))
+    }
+
+    /// Create a view of the points sorted with `compare`, sharing this view's point table.
+    /// The sort is stable.
+    pub fn sort_by<F>(&self, mut compare: F) -> Result<PointView>
+    where
+        F: FnMut(&PointRef, &PointRef) -> Ordering,
+    {
+        let mut points = self.points().collect::<Vec<_>>();
+        points.sort_by(|a, b| compare(a, b));
+        let ids = points.iter().map(PointRef::id).collect::<Vec<_>>();
+        self.subset(&ids)
+    }
+
+    /// Point IDs ordered along a Morton (Z-order) curve over the X/Y bounds of the view.
+    ///
+    /// Pass the result to [`PointView::subset`] to create a spatially ordered view.
+    pub fn morton_order(&self) -> Result<Vec<PointId>> {
+        self.spatial_order(morton_key)
+    }
+
+    /// Point IDs ordered along a Hilbert curve over the X/Y bounds of the view.
+    ///
+    /// Pass the result to [`PointView::subset`] to create a spatially ordered view.
+    pub fn hilbert_order(&self) -> Result<Vec<PointId>> {
+        self.spatial_order(hilbert_key)
+    }
+
+    fn spatial_order(&self, key: fn(u32, u32) -> u64) -> Result<Vec<PointId>> {
+        let xs = quantize(&self.dimension_values::<f64>(DimTypeId::X)?);
+        let ys = quantize(&self.dimension_values::<f64>(DimTypeId::Y)?);
+        let keys = xs
+            .into_iter()
+            .zip(ys)
+            .map(|(x, y)| key(x, y))
+            .collect::<Vec<_>>();
+        let mut ids = self.point_ids().collect::<Vec<_>>();
+        ids.sort_by_key(|&id| keys[id as usize]);
+        Ok(ids)
+    }
+}
+
+/// Map values onto the full `u32` range, relative to their bounds.
+fn quantize(values: &[f64]) -> Vec<u32> {
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+            (lo.min(v), hi.max(v))
+        });
+    let scale = if max > min {
+        u32::MAX as f64 / (max - min)
+    } else {
+        0.0
+    };
+    values.iter().map(|v| ((v - min) * scale) as u32).collect()
+}
+
+/// Spread the bits of `v` into the even bits of the result.
+fn spread_bits(v: u32) -> u64 {
+    let mut x = v as u64;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+fn morton_key(x: u32, y: u32) -> u64 {
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+/// Distance along a Hilbert curve filling the `2^32 x 2^32` grid.
+fn hilbert_key(x: u32, y: u32) -> u64 {
+    let (mut x, mut y) = (x as u64, y as u64);
+    let max = u32::MAX as u64;
+    let mut d = 0;
+    let mut s = 1u64 << 31;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        // Rotate the quadrant so the sub-curve is in standard orientation.
+        if ry == 0 {
+            if rx == 1 {
+                x = max - x;
+                y = max - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s >>= 1;
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hilbert_key, morton_key};
+    use crate::testkit::TestResult;
+    use crate::{DimTypeId, ExecLimits, Pipeline};
+
+    #[test]
+    fn test_curve_keys() {
+        let max = u32::MAX;
+        assert_eq!(morton_key(1, 0), 1);
+        assert_eq!(morton_key(0, 1), 2);
+        assert_eq!(morton_key(3, 3), 15);
+        assert_eq!(morton_key(max, max), u64::MAX);
+
+        assert_eq!(hilbert_key(0, 0), 0);
+        assert_eq!(hilbert_key(max, 0), u64::MAX);
+        assert!(hilbert_key(0, max) < hilbert_key(max, max));
+        assert!(hilbert_key(max, max) < hilbert_key(max, 0));
+    }
+
+    #[test]
+    fn test_sorting() -> TestResult {
+        let json = r#"["tests/data/autzen_trim.laz", {"type": "filters.head", "count": 500}]"#;
+        let result = Pipeline::new(json)?.execute()?;
+        let view = result.point_views()?.pop().ok_or("no point view")?;
+
+        let by_z = view.sort_by_dimension(DimTypeId::Z)?;
+        let zs = by_z.dimension_values::<f64>(DimTypeId::Z)?;
+        assert_eq!(zs.len(), view.len());
+        assert!(zs.windows(2).all(|w| w[0] <= w[1]));
+
+        let by_intensity = view.sort_by(|a, b| {
+            let ia = a.get::<u16>(DimTypeId::Intensity).unwrap_or_default();
+            let ib = b.get::<u16>(DimTypeId::Intensity).unwrap_or_default();
+            ib.cmp(&ia)
+        })?;
+        let intensities = by_intensity.dimension_values::<u16>(DimTypeId::Intensity)?;
+        assert!(intensities.windows(2).all(|w| w[0] >= w[1]));
+
+        for order in [view.morton_order()?, view.hilbert_order()?] {
+            let mut ids = order.clone();
+            ids.sort();
+            assert!(ids.into_iter().eq(view.point_ids()));
+            assert_eq!(view.subset(&order)?.len(), view.len());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_at_point_limit() -> TestResult {
+        let json =
+            r#"[{"type": "readers.las", "filename": "tests/data/autzen_trim.laz", "count": 500}]"#;
+        let mut pipeline = Pipeline::new(json)?;
+        pipeline.set_limits(ExecLimits {
+            max_points: Some(500),
+            ..Default::default()
+        })?;
+        let result = pipeline.execute()?;
+        let view = result.point_views()?.pop().ok_or("no point view")?;
+        assert_eq!(view.len(), 500);
+
+        // Sorting mustn't add points to the table, which is already at its limit.
+        for _ in 0..2 {
+            let sorted = view.sort_by_dimension(DimTypeId::Z)?;
+            let zs = sorted.dimension_values::<f64>(DimTypeId::Z)?;
+            assert!(zs.windows(2).all(|w| w[0] <= w[1]));
+        }
+        Ok(())
+    }
+}
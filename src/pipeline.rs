@@ -19,13 +19,33 @@
 
 use crate::error::Result;
 
-use crate::PointView;
-use pdal_sys::pipeline_manager::{PipelineManager, PipelineManagerPtr};
+use crate::{PointArray, PointView};
+#[cfg(feature = "tokio")]
+use pdal_sys::pipeline_manager::ExecutionMonitorPtr;
+use pdal_sys::pipeline_manager::{PipelineManager, PipelineManagerPtr, StageInfo};
+use pdal_sys::point_view::PointViewPtr;
+use serde_json::map::Entry;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use std::path::Path;
 
 /// Primary interface for constructing and executing PDAL pipelines.
 #[derive(Debug)]
-pub struct Pipeline(PipelineManagerPtr);
+pub struct Pipeline {
+    mgr: PipelineManagerPtr,
+    /// Views supplied via [`Pipeline::add_input`], retained for [`Pipeline::try_clone`].
+    inputs: Vec<InputView>,
+    limits: ExecLimits,
+}
+
+/// Handle on a view supplied via [`Pipeline::add_input`].
+#[derive(Debug, Clone)]
+struct InputView(PointViewPtr);
+
+// SAFETY: The handle is only cloned, never dereferenced from Rust, and PDAL only reads the view
+// through `const` accessors when executing the pipeline.
+unsafe impl Send for InputView {}
 
 impl Pipeline {
     /// Construct a new pipeline.
@@ -33,67 +53,477 @@ impl Pipeline {
         let pdal_json = pdal_json.as_ref();
         let mut mgr = PipelineManager::new();
         mgr.pin_mut().readPipeline(pdal_json)?;
-        Ok(Self(mgr))
+        Ok(Self {
+            mgr,
+            inputs: Vec::new(),
+            limits: ExecLimits::default(),
+        })
+    }
+
+    /// Create an independent, unexecuted copy of this pipeline.
+    ///
+    /// The copy is parsed from [`Pipeline::to_json`], so it reflects any option overrides, and
+    /// receives the same views supplied via [`Pipeline::add_input`]. Useful for executing the
+    /// same pipeline multiple times, e.g. with a different reader filename per run.
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut copy = Self::new(self.to_json())?;
+        copy.set_limits(self.limits)?;
+        for view in &self.inputs {
+            copy.mgr.pin_mut().addInput(&view.0)?;
+            copy.inputs.push(view.clone());
+        }
+        Ok(copy)
+    }
+
+    /// Get the JSON representation of the parsed pipeline, including option overrides.
+    ///
+    /// Untagged stages are assigned tags of the form `stage_<index>`, suffixed if already taken,
+    /// so inputs can be expressed.
+    /// Views supplied via [`Pipeline::add_input`] are not included.
+    pub fn to_json(&self) -> String {
+        let tags = reference_tags(&self.mgr.stages());
+        let json: Vec<Value> = self
+            .stages()
+            .iter()
+            .map(|s| {
+                let mut obj = Map::new();
+                obj.insert("type".into(), s.name.clone().into());
+                obj.insert("tag".into(), tags[s.index].clone().into());
+                if !s.input_tags.is_empty() {
+                    obj.insert("inputs".into(), s.input_tags.clone().into());
+                }
+                for (name, value) in &s.options {
+                    match obj.entry(name.clone()) {
+                        Entry::Vacant(e) => {
+                            e.insert(value.clone().into());
+                        }
+                        Entry::Occupied(mut e) => match e.get_mut() {
+                            Value::Array(values) => values.push(value.clone().into()),
+                            prev => *prev = Value::Array(vec![prev.take(), value.clone().into()]),
+                        },
+                    }
+                }
+                Value::Object(obj)
+            })
+            .collect();
+        serde_json::json!({ "pipeline": json }).to_string()
+    }
+
+    /// Set (or replace) an option on a parsed stage, identified by index or tag.
+    ///
+    /// ```no_run
+    /// # let mut pipeline = pdal::Pipeline::new(r#"["in.las", {"type": "writers.las", "tag": "out"}]"#)?;
+    /// pipeline.set_option(0, "filename", "tile_001.las")?;
+    /// pipeline.set_option("out", "filename", "tile_001_out.las")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_option<'s, S: Into<StageRef<'s>>>(
+        &mut self,
+        stage: S,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let index = match stage.into() {
+            StageRef::Index(index) => index,
+            StageRef::Tag(tag) => self
+                .stages()
+                .iter()
+                .find(|s| s.tag.as_deref() == Some(tag))
+                .map(|s| s.index)
+                .ok_or_else(|| format!("No stage tagged '{tag}'"))?,
+        };
+        self.mgr.pin_mut().setOption(index, name, value)?;
+        Ok(())
+    }
+
+    /// Set the `filename` option of the pipeline's reader.
+    ///
+    /// Fails unless the pipeline has exactly one reader.
+    pub fn set_reader_filename<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let readers: Vec<_> = self
+            .stages()
+            .into_iter()
+            .filter(|s| s.is_reader())
+            .collect();
+        match readers.as_slice() {
+            [reader] => self.set_option(reader.index, "filename", &path.as_ref().to_string_lossy()),
+            _ => Err(format!("Expected one reader, found {}", readers.len()).into()),
+        }
+    }
+
+    /// Supply a point view as input to the pipeline.
+    ///
+    /// The view is read by the pipeline's root stage(s), allowing pipelines consisting solely of
+    /// filters and writers to process points constructed outside of PDAL.
+    pub fn add_input(&mut self, view: &PointView) -> Result<()> {
+        self.mgr.pin_mut().addInput(&view.0)?;
+        self.inputs.push(InputView(view.0.clone()));
+        Ok(())
+    }
+
+    /// Get the stages of the parsed pipeline, in declaration order.
+    pub fn stages(&self) -> Vec<PipelineStage> {
+        let infos = self.mgr.stages();
+        let tags = reference_tags(&infos);
+        infos
+            .into_iter()
+            .enumerate()
+            .map(|(index, info)| PipelineStage::new(index, info, &tags))
+            .collect()
+    }
+
+    /// Get the stages without inputs, typically readers.
+    pub fn roots(&self) -> Vec<PipelineStage> {
+        self.select(self.mgr.roots())
+    }
+
+    /// Get the stages whose output isn't consumed by another stage, typically writers.
+    pub fn leaves(&self) -> Vec<PipelineStage> {
+        self.select(self.mgr.leaves())
+    }
+
+    fn select(&self, indices: Vec<usize>) -> Vec<PipelineStage> {
+        let stages = self.stages();
+        indices.into_iter().map(|i| stages[i].clone()).collect()
+    }
+
+    /// Bound the memory used when executing the pipeline.
+    ///
+    /// ```no_run
+    /// # let mut pipeline = pdal::Pipeline::new(r#"["in.las"]"#)?;
+    /// pipeline.set_limits(pdal::ExecLimits {
+    ///     max_bytes: Some(512 * 1024 * 1024),
+    ///     ..Default::default()
+    /// })?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_limits(&mut self, limits: ExecLimits) -> Result<()> {
+        self.mgr.pin_mut().setLimits(
+            limits.stream_chunk_size,
+            limits.max_points.unwrap_or(0),
+            limits.max_bytes.unwrap_or(0),
+        )?;
+        self.limits = limits;
+        Ok(())
+    }
+
+    /// Get the current execution limits.
+    pub fn limits(&self) -> ExecLimits {
+        self.limits
+    }
+
+    /// Get a handle for observing and cancelling execution from another thread.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn monitor(&mut self) -> ExecutionMonitorPtr {
+        self.mgr.pin_mut().monitor()
+    }
+
+    /// Redirect the PDAL log to the execution monitor.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn capture_log(&mut self) {
+        self.mgr.pin_mut().captureLog()
     }
 
     /// Determine if the pipeline is streamable.
     pub fn is_streamable(&self) -> bool {
-        self.0.pipelineStreamable()
+        self.mgr.pipelineStreamable()
+    }
+
+    /// Execute the pipeline in the given mode.
+    ///
+    /// [`ExecMode::Stream`] fails before reading any points if the pipeline can't be streamed.
+    pub fn execute_with(self, mode: ExecMode) -> Result<ExecutedPipeline> {
+        match mode {
+            ExecMode::Standard => self.execute(),
+            ExecMode::PreferStream => self.execute_streamed(),
+            ExecMode::Stream => {
+                if !self.is_streamable() {
+                    let stages = self.stages();
+                    let mut names: Vec<_> = self
+                        .mgr
+                        .nonStreamableStages()
+                        .into_iter()
+                        .map(|i| match &stages[i].tag {
+                            Some(tag) => format!("{} ({tag})", stages[i].name),
+                            None => stages[i].name.clone(),
+                        })
+                        .collect();
+                    if !self.inputs.is_empty() {
+                        names.push("point view input".into());
+                    }
+                    return Err(if names.is_empty() {
+                        "Pipeline is not streamable".into()
+                    } else {
+                        format!("Pipeline is not streamable: {}", names.join(", ")).into()
+                    });
+                }
+                let result = self.execute_streamed()?;
+                if !result.is_streamed() {
+                    return Err("Pipeline was not executed in stream mode".into());
+                }
+                Ok(result)
+            }
+        }
     }
 
     /// Execute the pipeline.
     ///
     /// Returns the number of points produced.
     pub fn execute(mut self) -> Result<ExecutedPipeline> {
-        let p_count = self.0.pin_mut().execute()?;
-        Ok(ExecutedPipeline::new(self, p_count))
+        let p_count = self.mgr.pin_mut().execute()?;
+        Ok(ExecutedPipeline::new(self, Some(p_count), false))
     }
 
     /// Executes a pipeline as a streamable pipeline. Will run as non-streamed pipeline if the pipeline is not streamable.
     ///
-    /// Note: number of points produced is not available when run in streamed mode.
+    /// Use [`ExecutedPipeline::is_streamed`] to determine which mode was used; point views are
+    /// only available from non-streamed runs.
     pub fn execute_streamed(mut self) -> Result<ExecutedPipeline> {
-        self.0.pin_mut().executeStreamed()?;
-        Ok(ExecutedPipeline::new(self, 0))
+        let streamed = self.mgr.pin_mut().executeStreamed()?;
+        let points = if streamed {
+            None
+        } else {
+            Some(self.mgr.views()?.iter().map(|v| PointView(v).len()).sum())
+        };
+        Ok(ExecutedPipeline::new(self, points, streamed))
+    }
+}
+
+/// Pipeline execution strategy. See [`Pipeline::execute_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecMode {
+    /// Load all points into memory, retaining the resulting point views.
+    #[default]
+    Standard,
+    /// Stream points through the pipeline if possible, otherwise fall back to `Standard`.
+    PreferStream,
+    /// Stream points through the pipeline, failing if any stage isn't streamable.
+    Stream,
+}
+
+/// Resource limits applied when executing a [`Pipeline`]. See [`Pipeline::set_limits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecLimits {
+    /// Number of points held in memory at once when streaming.
+    pub stream_chunk_size: usize,
+    /// Maximum number of points loaded in standard (non-streamed) mode.
+    pub max_points: Option<u64>,
+    /// Maximum bytes of point data loaded in standard (non-streamed) mode.
+    pub max_bytes: Option<u64>,
+}
+
+impl Default for ExecLimits {
+    fn default() -> Self {
+        Self {
+            stream_chunk_size: 10_000,
+            max_points: None,
+            max_bytes: None,
+        }
+    }
+}
+
+/// Identifies a stage in a [`Pipeline`] by index or tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageRef<'s> {
+    Index(usize),
+    Tag(&'s str),
+}
+
+impl From<usize> for StageRef<'_> {
+    fn from(index: usize) -> Self {
+        StageRef::Index(index)
     }
 }
 
+impl<'s> From<&'s str> for StageRef<'s> {
+    fn from(tag: &'s str) -> Self {
+        StageRef::Tag(tag)
+    }
+}
+
+/// Description of a stage within a [`Pipeline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineStage {
+    /// Position of the stage within [`Pipeline::stages`].
+    pub index: usize,
+    /// Stage type name, e.g. `readers.las`.
+    pub name: String,
+    /// Tag assigned in the pipeline JSON, if any.
+    pub tag: Option<String>,
+    /// Indices of the stages providing input to this one.
+    pub inputs: Vec<usize>,
+    /// Tags of the stages providing input to this one, ordered as `inputs`. Untagged stages are
+    /// referred to by the tags assigned in [`Pipeline::to_json`].
+    pub input_tags: Vec<String>,
+    /// Options as `(name, value)` pairs. Names may repeat.
+    pub options: Vec<(String, String)>,
+}
+
+impl PipelineStage {
+    fn new(index: usize, info: StageInfo, tags: &[String]) -> Self {
+        Self {
+            index,
+            name: info.name,
+            tag: (!info.tag.is_empty()).then_some(info.tag),
+            input_tags: info.inputs.iter().map(|&i| tags[i].clone()).collect(),
+            inputs: info.inputs,
+            options: info
+                .options
+                .into_iter()
+                .map(|o| (o.name, o.value))
+                .collect(),
+        }
+    }
+
+    /// Get the first value of the named option.
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Get the `filename` option, if set.
+    pub fn filename(&self) -> Option<&str> {
+        self.option("filename")
+    }
+
+    /// Determine if the stage is a reader, e.g. `readers.las`.
+    pub fn is_reader(&self) -> bool {
+        self.name.starts_with("readers.")
+    }
+
+    /// Determine if the stage is a filter, e.g. `filters.range`.
+    pub fn is_filter(&self) -> bool {
+        self.name.starts_with("filters.")
+    }
+
+    /// Determine if the stage is a writer, e.g. `writers.las`.
+    pub fn is_writer(&self) -> bool {
+        self.name.starts_with("writers.")
+    }
+}
+
+/// Tags used to refer to each stage, assigning `stage_<index>` to untagged stages, with a
+/// `_<n>` suffix if that collides with another tag.
+fn reference_tags(infos: &[StageInfo]) -> Vec<String> {
+    let mut used: HashSet<String> = infos
+        .iter()
+        .filter(|info| !info.tag.is_empty())
+        .map(|info| info.tag.clone())
+        .collect();
+    infos
+        .iter()
+        .enumerate()
+        .map(|(index, info)| {
+            if !info.tag.is_empty() {
+                return info.tag.clone();
+            }
+            let base = format!("stage_{index}");
+            let mut tag = base.clone();
+            let mut suffix = 1;
+            while used.contains(&tag) {
+                tag = format!("{base}_{suffix}");
+                suffix += 1;
+            }
+            used.insert(tag.clone());
+            tag
+        })
+        .collect()
+}
+
 /// A [`Pipeline`] after it has been executed, thereby accessible for further inspection.
 #[derive(Debug)]
 pub struct ExecutedPipeline {
     pipeline: Pipeline,
     points: usize,
+    streamed: bool,
+    stage_points: Vec<Option<u64>>,
 }
 
 impl ExecutedPipeline {
-    pub(crate) fn new(pipeline: Pipeline, points: usize) -> Self {
-        Self { pipeline, points }
+    pub(crate) fn new(pipeline: Pipeline, points: Option<usize>, streamed: bool) -> Self {
+        let stage_points: Vec<Option<u64>> = pipeline
+            .mgr
+            .stagePointCounts()
+            .into_iter()
+            .map(|c| u64::try_from(c).ok())
+            .collect();
+
+        // Streamed runs don't retain point views, so use the points produced by the leaves.
+        let points = points.unwrap_or_else(|| {
+            pipeline
+                .mgr
+                .leaves()
+                .iter()
+                .filter_map(|l| stage_points[*l])
+                .sum::<u64>() as usize
+        });
+
+        Self { pipeline, points, streamed, stage_points }
     }
 
     /// Get the number of points produced by the pipeline.
+    ///
+    /// For streamed runs this is the number of points produced by the pipeline's final stage.
     pub fn point_count(&self) -> usize {
         self.points
     }
 
+    /// Determine if the pipeline was run in stream mode.
+    ///
+    /// Streamed runs don't retain points, so [`ExecutedPipeline::point_views`] is empty.
+    pub fn is_streamed(&self) -> bool {
+        self.streamed
+    }
+
+    /// Get the number of points produced by each stage, ordered as [`Pipeline::stages`].
+    ///
+    /// The count is `None` if none was recorded for the stage.
+    pub fn stage_point_counts(&self) -> Vec<(PipelineStage, Option<u64>)> {
+        self.pipeline
+            .stages()
+            .into_iter()
+            .zip(self.stage_points.iter().copied())
+            .collect()
+    }
+
     /// Get an iterator over the point views produced by the pipeline.
     pub fn point_views(&self) -> Result<Vec<PointView>> {
-        let v = self.pipeline.0.views()?;
+        let v = self.pipeline.mgr.views()?;
         Ok(v.iter().map(PointView).collect())
     }
 
+    /// Get a columnar copy of each point view produced by the pipeline.
+    ///
+    /// See [`PointArray`] for details.
+    pub fn arrays(&self) -> Result<Vec<PointArray>> {
+        self.point_views()?
+            .iter()
+            .map(PointArray::from_view)
+            .collect()
+    }
+
+    /// Create a fresh, unexecuted copy of the pipeline that produced these results.
+    ///
+    /// See [`Pipeline::try_clone`].
+    pub fn to_pipeline(&self) -> Result<Pipeline> {
+        self.pipeline.try_clone()
+    }
+
     /// Retrieves a pipeline's computed metadata
     pub fn metadata(&self) -> Result<String> {
-        Ok(self.pipeline.0.metadata()?)
+        Ok(self.pipeline.mgr.metadata()?)
     }
 
     /// Retrieves the full json string representation of an execute pipeline
     pub fn pipeline_json(&self) -> Result<String> {
-        Ok(self.pipeline.0.pipeline()?)
+        Ok(self.pipeline.mgr.pipeline()?)
     }
 
     /// Retrieves a pipeline's computed schema.
     pub fn schema(&self) -> Result<String> {
-        Ok(self.pipeline.0.schema()?)
+        Ok(self.pipeline.mgr.schema()?)
     }
 }
 
@@ -106,7 +536,7 @@ impl Display for ExecutedPipeline {
 #[cfg(test)]
 mod test {
     use crate::testkit::{read_test_file, TestResult};
-    use crate::Pipeline;
+    use crate::{ExecLimits, ExecMode, Pipeline};
     #[test]
     fn test_validate_pipeline() -> TestResult {
         let json = read_test_file("invalid.json");
@@ -121,6 +551,186 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_pipeline_stages() -> TestResult {
+        let json = read_test_file("stats.json");
+        let pipeline = Pipeline::new(json)?;
+        let stages = pipeline.stages();
+        assert_eq!(stages.len(), 3);
+        assert!(stages[0].is_reader());
+        assert_eq!(stages[0].name, "readers.las");
+        assert!(stages[0].filename().unwrap().ends_with("autzen_trim.laz"));
+        assert!(stages[1].is_filter());
+        assert_eq!(stages[1].inputs, vec![0]);
+        assert!(stages[2].is_writer());
+        assert_eq!(stages[2].inputs, vec![1]);
+
+        let roots = pipeline.roots();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].index, 0);
+        let leaves = pipeline.leaves();
+        assert_eq!(leaves.len(), 1);
+        assert_eq!(leaves[0].name, "writers.null");
+
+        let json = r#"["in.las", {"type": "filters.range", "tag": "ground"}, "out.las"]"#;
+        let stages = Pipeline::new(json)?.stages();
+        assert_eq!(stages[1].tag.as_deref(), Some("ground"));
+        assert_eq!(stages[2].input_tags, vec!["ground"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_option() -> TestResult {
+        let json = r#"{ "pipeline": [
+            { "type": "readers.las" },
+            { "type": "filters.stats", "tag": "stats" },
+            { "type": "writers.null" }
+        ] }"#;
+        let mut pipeline = Pipeline::new(json)?;
+        pipeline.set_reader_filename("tests/data/autzen_trim.laz")?;
+        pipeline.set_option("stats", "dimensions", "Intensity")?;
+        assert!(pipeline.set_option("missing", "dimensions", "X").is_err());
+
+        let stages = pipeline.stages();
+        assert_eq!(stages[0].filename(), Some("tests/data/autzen_trim.laz"));
+        assert_eq!(stages[1].option("dimensions"), Some("Intensity"));
+
+        let result = pipeline.execute()?;
+        assert_eq!(result.point_count(), 110000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_clone() -> TestResult {
+        let json = r#"[ "tests/data/autzen_trim.laz",
+            { "type": "filters.range", "limits": "Intensity[0:]", "tag": "range" },
+            { "type": "writers.null" } ]"#;
+        let pipeline = Pipeline::new(json)?;
+        let copy = pipeline.try_clone()?;
+        assert_eq!(copy.stages().len(), 3);
+        assert_eq!(copy.stages()[1].tag.as_deref(), Some("range"));
+        assert_eq!(copy.stages()[2].inputs, vec![1]);
+        assert_eq!(copy.stages()[1].option("limits"), Some("Intensity[0:]"));
+
+        let first = pipeline.execute()?;
+        let again = first.to_pipeline()?.execute()?;
+        assert_eq!(first.point_count(), again.point_count());
+        assert_eq!(copy.execute()?.point_count(), 110000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_tags() -> TestResult {
+        let json = r#"[
+            { "type": "readers.las", "filename": "tests/data/autzen_trim.laz", "tag": "stage_1" },
+            { "type": "filters.range", "limits": "Classification[2:2]" },
+            { "type": "writers.null" } ]"#;
+        let pipeline = Pipeline::new(json)?;
+        let stages = pipeline.stages();
+        assert_eq!(stages[1].input_tags, vec!["stage_1"]);
+        assert_eq!(stages[2].input_tags, vec!["stage_1_1"]);
+
+        let copy = pipeline.try_clone()?;
+        let stages = copy.stages();
+        assert_eq!(stages[1].inputs, vec![0]);
+        assert_eq!(stages[2].inputs, vec![1]);
+        assert_eq!(stages[1].tag.as_deref(), Some("stage_1_1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_streamed_execution() -> TestResult {
+        let json = r#"[ "tests/data/autzen_trim.laz",
+            { "type": "filters.range", "limits": "Classification[2:2]" },
+            { "type": "writers.null" } ]"#;
+        let pipeline = Pipeline::new(json)?;
+        assert!(pipeline.is_streamable());
+        let result = pipeline.execute_streamed()?;
+        assert!(result.is_streamed());
+        assert!(result.point_views()?.is_empty());
+        assert!(!result.metadata()?.is_empty());
+
+        let counts: Vec<_> = result
+            .stage_point_counts()
+            .into_iter()
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(counts[0], Some(110000));
+        let ground = counts[1].expect("filter count");
+        assert!(ground > 0 && ground < 110000);
+        assert_eq!(counts[2], Some(ground));
+        assert_eq!(result.point_count(), ground as usize);
+
+        // Without a writer, the filter's output is the pipeline's output.
+        let json = r#"[ "tests/data/autzen_trim.laz",
+            { "type": "filters.range", "limits": "Classification[2:2]" } ]"#;
+        let result = Pipeline::new(json)?.execute_streamed()?;
+        assert!(result.is_streamed());
+        assert_eq!(result.stage_point_counts()[1].1, Some(ground));
+        assert_eq!(result.point_count(), ground as usize);
+
+        // Not streamable, so falls back to standard mode.
+        let json =
+            r#"[ "tests/data/autzen_trim.laz", { "type": "filters.sort", "dimension": "X" } ]"#;
+        let result = Pipeline::new(json)?.execute_streamed()?;
+        assert!(!result.is_streamed());
+        assert_eq!(result.point_count(), 110000);
+        assert_eq!(result.stage_point_counts()[1].1, Some(110000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_with() -> TestResult {
+        let streamable = r#"[ "tests/data/autzen_trim.laz", { "type": "writers.null" } ]"#;
+        let result = Pipeline::new(streamable)?.execute_with(ExecMode::Stream)?;
+        assert!(result.is_streamed());
+        let result = Pipeline::new(streamable)?.execute_with(ExecMode::Standard)?;
+        assert!(!result.is_streamed());
+        assert_eq!(result.point_views()?.len(), 1);
+
+        let json = r#"[ "tests/data/autzen_trim.laz",
+            { "type": "filters.sort", "dimension": "X", "tag": "sorter" } ]"#;
+        let err = Pipeline::new(json)?
+            .execute_with(ExecMode::Stream)
+            .unwrap_err();
+        assert!(err.to_string().contains("filters.sort (sorter)"), "{err}");
+        let result = Pipeline::new(json)?.execute_with(ExecMode::PreferStream)?;
+        assert!(!result.is_streamed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_limits() -> TestResult {
+        let json = read_test_file("stats.json");
+        let mut pipeline = Pipeline::new(&json)?;
+        assert!(pipeline
+            .set_limits(ExecLimits {
+                stream_chunk_size: 0,
+                ..Default::default()
+            })
+            .is_err());
+        pipeline.set_limits(ExecLimits {
+            max_points: Some(1000),
+            ..Default::default()
+        })?;
+        let err = pipeline.try_clone()?.execute().unwrap_err();
+        assert!(err.to_string().contains("Point limit"), "{err}");
+        // Streaming isn't bound by the standard-mode limits.
+        let result = pipeline.execute_with(ExecMode::Stream)?;
+        assert_eq!(result.point_count(), 110000);
+
+        let mut pipeline = Pipeline::new(&json)?;
+        pipeline.set_limits(ExecLimits {
+            stream_chunk_size: 100,
+            max_bytes: Some(1 << 30),
+            ..Default::default()
+        })?;
+        let result = pipeline.execute()?;
+        assert_eq!(result.point_count(), 110000);
+        assert!(result.schema()?.contains("dimensions"));
+        Ok(())
+    }
+
     #[test]
     fn test_pipeline_execution() -> TestResult {
         let json = read_test_file("stats.json");
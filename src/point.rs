@@ -0,0 +1,255 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::error::Result;
+use crate::{DimTypeEncoding, Dimension, PdalPrimitive, PdalType, PdalValue, PointId, PointView};
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+/// A dimension of a view's layout, looked up once per iteration.
+#[derive(Debug)]
+pub(crate) struct PointDimension {
+    pub(crate) dim: Dimension,
+    pub(crate) encoding: DimTypeEncoding,
+    pub(crate) name: String,
+}
+
+pub(crate) type PointDimensions = Arc<[PointDimension]>;
+
+pub(crate) fn point_dimensions(view: &PointView) -> PointDimensions {
+    let layout = view.0.layout();
+    layout
+        .dim_types()
+        .map(|dt| PointDimension {
+            dim: dt.id(),
+            encoding: dt.encoding(),
+            name: layout.dim_name(dt.id()),
+        })
+        .collect()
+}
+
+/// Reference to a single point within a [`PointView`].
+///
+/// See [`PointView::points`].
+#[derive(Clone)]
+pub struct PointRef<'pv> {
+    view: &'pv PointView,
+    id: PointId,
+    dims: PointDimensions,
+}
+
+impl<'pv> PointRef<'pv> {
+    pub(crate) fn new(view: &'pv PointView, id: PointId, dims: PointDimensions) -> Self {
+        Self { view, id, dims }
+    }
+
+    /// ID of the point within its view.
+    pub fn id(&self) -> PointId {
+        self.id
+    }
+
+    /// The point view the point belongs to.
+    pub fn view(&self) -> &'pv PointView {
+        self.view
+    }
+
+    /// Fetch a dimension value as the specified primitive type.
+    pub fn get<T: PdalType>(&self, dim: impl Into<Dimension>) -> Result<T> {
+        self.view.point_value_as(dim, self.id)
+    }
+
+    /// Fetch a dimension value, failing unless the dimension is stored as exactly `T`.
+    ///
+    /// See [`PointView::get_exact`].
+    pub fn get_exact<T: PdalPrimitive>(&self, dim: impl Into<Dimension>) -> Result<T> {
+        self.view.get_exact(dim, self.id)
+    }
+
+    /// Fetch a dimension value with PDAL's range-checked conversion.
+    ///
+    /// See [`PointView::get_converted`].
+    pub fn get_converted<T: PdalPrimitive>(&self, dim: impl Into<Dimension>) -> Result<T> {
+        self.view.get_converted(dim, self.id)
+    }
+
+    /// Fetch a dimension value, clamping it to the range of `T`.
+    ///
+    /// See [`PointView::get_lossy`].
+    pub fn get_lossy<T: PdalPrimitive>(&self, dim: impl Into<Dimension>) -> Result<T> {
+        self.view.get_lossy(dim, self.id)
+    }
+
+    /// Fetch a dimension value in its native encoding.
+    pub fn get_value(&self, dim: impl Into<Dimension>) -> Result<PdalValue> {
+        let dim = dim.into();
+        let encoding = self
+            .encoding(dim)
+            .ok_or_else(|| format!("Dimension {dim} is not in the point layout"))?;
+        Ok(self.view.0.point_value_encoded(dim, encoding, self.id)?)
+    }
+
+    /// The dimensions available on the point.
+    pub fn dimensions(&self) -> impl Iterator<Item = Dimension> + '_ {
+        self.dims.iter().map(|d| d.dim)
+    }
+
+    /// Iterator over every dimension of the point with its value, in layout order.
+    pub fn values(&self) -> impl Iterator<Item = Result<(Dimension, PdalValue)>> + '_ {
+        self.named_values()
+            .map(|r| r.map(|(d, value)| (d.dim, value)))
+    }
+
+    /// Like [`PointRef::values`], with the layout's description of each dimension.
+    pub(crate) fn named_values(
+        &self,
+    ) -> impl Iterator<Item = Result<(&PointDimension, PdalValue)>> + '_ {
+        self.dims.iter().map(|d| {
+            let value = self
+                .view
+                .0
+                .point_value_encoded(d.dim, d.encoding, self.id)?;
+            Ok((d, value))
+        })
+    }
+
+    fn encoding(&self, dim: Dimension) -> Option<DimTypeEncoding> {
+        self.dims.iter().find(|d| d.dim == dim).map(|d| d.encoding)
+    }
+}
+
+/// Serialized as a map of dimension name to value.
+impl Serialize for PointRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.dims.len()))?;
+        for entry in self.named_values() {
+            let (dim, value) = entry.map_err(S::Error::custom)?;
+            map.serialize_entry(&dim.name, &value)?;
+        }
+        map.end()
+    }
+}
+
+impl Debug for PointRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointRef")
+            .field("view", &self.view.id())
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+/// Iterator over the points in a [`PointView`].
+///
+/// See [`PointView::points`].
+#[derive(Clone)]
+pub struct Points<'pv> {
+    view: &'pv PointView,
+    ids: std::ops::Range<PointId>,
+    dims: PointDimensions,
+}
+
+impl<'pv> Points<'pv> {
+    pub(crate) fn new(view: &'pv PointView) -> Self {
+        Self {
+            view,
+            ids: 0..view.len() as PointId,
+            dims: point_dimensions(view),
+        }
+    }
+}
+
+impl<'pv> Iterator for Points<'pv> {
+    type Item = PointRef<'pv>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.ids.next()?;
+        Some(PointRef::new(self.view, id, self.dims.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Points<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let id = self.ids.next_back()?;
+        Some(PointRef::new(self.view, id, self.dims.clone()))
+    }
+}
+
+impl ExactSizeIterator for Points<'_> {}
+impl FusedIterator for Points<'_> {}
+
+impl Debug for Points<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Points")
+            .field("view", &self.view.id())
+            .field("ids", &self.ids)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{read_test_file, TestResult};
+    use crate::{DimTypeId, Dimension, PdalValue, Pipeline};
+
+    #[test]
+    fn test_points() -> TestResult {
+        let pipeline = Pipeline::new(read_test_file("stats.json"))?;
+        let results = pipeline.execute()?;
+        let view = results.point_views()?.pop().ok_or("no point view")?;
+
+        let points = view.points();
+        assert_eq!(points.len(), 110000);
+
+        let point = view.points().nth(4).ok_or("no point")?;
+        assert_eq!(point.id(), 4);
+        let x: f64 = point.get(DimTypeId::X)?;
+        assert_eq!(x, view.point_value_as::<f64>(DimTypeId::X, 4)?);
+        assert_eq!(
+            point.get_value(DimTypeId::Intensity)?,
+            view.point_value(DimTypeId::Intensity, 4)?
+        );
+        assert!(matches!(
+            point.get_value(DimTypeId::Classification)?,
+            PdalValue::Unsigned8(_)
+        ));
+        assert!(point.get_value(DimTypeId::Amplitude).is_err());
+        assert_eq!(
+            point.get_converted::<u32>(DimTypeId::Intensity)?,
+            u32::from(point.get_exact::<u16>(DimTypeId::Intensity)?)
+        );
+        assert!(point.get_exact::<f32>(DimTypeId::X).is_err());
+
+        let values = point.values().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(values.len(), view.layout()?.dimension_count());
+        assert!(values
+            .iter()
+            .any(|(dim, _)| *dim == Dimension::from(DimTypeId::Z)));
+
+        let last = view.points().next_back().ok_or("no point")?;
+        assert_eq!(last.id(), 109999);
+        Ok(())
+    }
+}
@@ -19,8 +19,9 @@
 
 use crate::error::Result;
 use crate::utils::Elided;
-use crate::{DimTypeId, PointLayout};
-use std::fmt::{Debug, Formatter};
+use crate::{DimTypeEncoding, Dimension, PointArray, PointLayout, PointRef, Points};
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Display, Formatter};
 
 /// Unique identifier for a point in a point view.
 
@@ -28,6 +29,7 @@ pub type PointId = pdal_sys::core::PointId;
 
 pub use pdal_sys::core::PdalType;
 pub use pdal_sys::core::PdalValue;
+pub use pdal_sys::core::{ConversionError, PdalPrimitive};
 
 /// A point view is a collection of points with a common layout.
 pub struct PointView(pub(crate) pdal_sys::point_view::PointViewPtr);
@@ -43,6 +45,13 @@ impl PointView {
         self.0.len() as usize
     }
 
+    /// Iterator over the points in the view.
+    ///
+    /// The view's layout is looked up once, rather than for every dimension access.
+    pub fn points(&self) -> Points<'_> {
+        Points::new(self)
+    }
+
     /// Iterator over the valid point IDs
     pub fn point_ids(&self) -> impl Iterator<Item = PointId> {
         (0..self.len()).map(|i| i as PointId)
@@ -70,16 +79,153 @@ impl PointView {
     }
 
     /// Fetch the the dimension value of the point at the given index as the specified primitive type.
-    pub fn point_value_as<T: PdalType>(&self, dim: DimTypeId, idx: PointId) -> Result<T> {
+    pub fn point_value_as<T: PdalType>(
+        &self,
+        dim: impl Into<Dimension>,
+        idx: PointId,
+    ) -> Result<T> {
         Ok(self.0.point_value_as(dim, idx)?)
     }
 
+    /// Fetch a dimension value, failing unless the dimension is stored as exactly `T`.
+    pub fn get_exact<T: PdalPrimitive>(
+        &self,
+        dim: impl Into<Dimension>,
+        idx: PointId,
+    ) -> Result<T> {
+        let dim = dim.into();
+        let encoding = self.encoding_of(dim, idx)?;
+        if encoding != T::encoding() {
+            return Err(self.value_error::<T>(dim, idx, format!("stored as {}", encoding.name())));
+        }
+        self.get_converted(dim, idx)
+    }
+
+    /// Fetch a dimension value with PDAL's conversion semantics: floating point values are
+    /// rounded, and values out of the range of `T` are an error.
+    pub fn get_converted<T: PdalPrimitive>(
+        &self,
+        dim: impl Into<Dimension>,
+        idx: PointId,
+    ) -> Result<T> {
+        let dim = dim.into();
+        self.0
+            .point_value_as(dim, idx)
+            .map_err(|e| self.value_error::<T>(dim, idx, e))
+    }
+
+    /// Fetch a dimension value, clamping it to the range of `T`.
+    ///
+    /// See [`PdalValue::saturating_cast`].
+    pub fn get_lossy<T: PdalPrimitive>(
+        &self,
+        dim: impl Into<Dimension>,
+        idx: PointId,
+    ) -> Result<T> {
+        let dim = dim.into();
+        let encoding = self.encoding_of(dim, idx)?;
+        let value = self
+            .0
+            .point_value_encoded(dim, encoding, idx)
+            .map_err(|e| self.value_error::<T>(dim, idx, e))?;
+        Ok(value.saturating_cast())
+    }
+
+    fn encoding_of(&self, dim: Dimension, idx: PointId) -> Result<DimTypeEncoding> {
+        match self.0.layout().dimEncoding(dim) {
+            DimTypeEncoding::None => Err(format!(
+                "Can't read dimension {dim} of point {idx}: not in the point layout"
+            )
+            .into()),
+            encoding => Ok(encoding),
+        }
+    }
+
+    fn value_error<T: PdalType>(
+        &self,
+        dim: Dimension,
+        idx: PointId,
+        reason: impl Display,
+    ) -> crate::error::Error {
+        format!(
+            "Can't read dimension {} of point {idx} as {}: {reason}",
+            self.0.layout().dim_name(dim),
+            T::encoding().name()
+        )
+        .into()
+    }
+
+    /// Fetch the values of a dimension for every point in the view as the specified primitive type.
+    pub fn dimension_values<T: PdalType + Copy + Default>(
+        &self,
+        dim: impl Into<Dimension>,
+    ) -> Result<Vec<T>> {
+        Ok(self.0.point_values_as(dim)?)
+    }
+
+    /// Fill `out` with the values of a dimension for consecutive points starting at `start`.
+    pub fn read_dimension_values<T: PdalType>(
+        &self,
+        dim: impl Into<Dimension>,
+        start: PointId,
+        out: &mut [T],
+    ) -> Result<()> {
+        Ok(self.0.read_values_as(dim, start, out)?)
+    }
+
+    /// Create an empty view sharing this view's point table and spatial reference.
+    pub fn make_new(&self) -> PointView {
+        PointView(pdal_sys::point_view::makeNew(&self.0))
+    }
+
+    /// Create a view of the given points, sharing this view's point table.
+    ///
+    /// Points are referenced rather than copied, in the order given.
+    pub fn subset(&self, ids: &[PointId]) -> Result<PointView> {
+        Ok(PointView(pdal_sys::point_view::subset(&self.0, ids)?))
+    }
+
+    /// Create a view of the points matching `predicate`, sharing this view's point table.
+    pub fn filter<F>(&self, mut predicate: F) -> Result<PointView>
+    where
+        F: FnMut(&PointRef) -> bool,
+    {
+        let ids = self
+            .points()
+            .filter(|p| predicate(p))
+            .map(|p| p.id())
+            .collect::<Vec<_>>();
+        self.subset(&ids)
+    }
+
+    /// Create a view of the points of this view followed by those of `other`.
+    ///
+    /// Both views must share the same point table, e.g. by being derived from the same view.
+    /// Unlike `pdal::PointView::append`, neither view is modified; a new view is returned.
+    pub fn append(&self, other: &PointView) -> Result<PointView> {
+        Ok(PointView(pdal_sys::point_view::append(&self.0, &other.0)?))
+    }
+
+    /// Copy all dimensions of the view into a columnar [`PointArray`].
+    pub fn to_array(&self) -> Result<PointArray> {
+        PointArray::from_view(self)
+    }
+
     /// Fetch The the dimension value of the point at the given index as a wrapped [`PdalValue`].
-    pub fn point_value(&self, dim: DimTypeId, idx: PointId) -> Result<PdalValue> {
+    pub fn point_value(&self, dim: impl Into<Dimension>, idx: PointId) -> Result<PdalValue> {
         Ok(self.0.point_value(dim, idx)?)
     }
 }
 
+/// Serialized as a sequence of points, each a map of dimension name to value.
+///
+/// For a columnar representation, serialize [`PointView::to_array`] instead.
+impl Serialize for PointView {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.points())
+    }
+}
+
 impl Debug for PointView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PointView")
@@ -93,7 +239,7 @@ impl Debug for PointView {
 #[cfg(test)]
 mod tests {
     use crate::testkit::{read_test_file, TestResult};
-    use crate::{error::Result, DimTypeId, ExecutedPipeline, Pipeline};
+    use crate::{error::Result, DimTypeId, ExecutedPipeline, Pipeline, PointId};
     use pdal_sys::core::PdalValue;
 
     fn fixture() -> Result<ExecutedPipeline> {
@@ -115,6 +261,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_serialize() -> TestResult {
+        let json = r#"["tests/data/autzen_trim.laz", {"type": "filters.head", "count": 3}]"#;
+        let result = Pipeline::new(json)?.execute()?;
+        let view = result.point_views()?.pop().ok_or("no point view")?;
+
+        let rows = serde_json::to_value(&view)?;
+        let rows = rows.as_array().ok_or("expected array")?;
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[1]["Intensity"],
+            view.point_value_as::<u16>(DimTypeId::Intensity, 1)?
+        );
+
+        let columns = serde_json::to_value(view.to_array()?)?;
+        assert_eq!(columns["X"].as_array().map(Vec::len), Some(3));
+        assert_eq!(columns["X"][2], rows[2]["X"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_reads() -> TestResult {
+        let result = fixture()?;
+        let views = result.point_views()?;
+        let view = views.first().ok_or("no point view")?;
+
+        let intensity = view.get_exact::<u16>(DimTypeId::Intensity, 0)?;
+        let err = view
+            .get_exact::<u32>(DimTypeId::Intensity, 0)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Intensity") && err.contains("point 0"),
+            "{err}"
+        );
+        assert_eq!(
+            view.get_converted::<u32>(DimTypeId::Intensity, 0)?,
+            u32::from(intensity)
+        );
+
+        let x = view.get_exact::<f64>(DimTypeId::X, 0)?;
+        assert!(x > 255.0);
+        let err = view.get_converted::<u8>(DimTypeId::X, 0).unwrap_err();
+        assert!(err.to_string().contains("dimension X of point 0"), "{err}");
+        assert_eq!(view.get_lossy::<u8>(DimTypeId::X, 0)?, u8::MAX);
+        assert_eq!(view.get_lossy::<f32>(DimTypeId::X, 0)?, x as f32);
+
+        let err = view.get_lossy::<u8>(DimTypeId::Amplitude, 1).unwrap_err();
+        assert!(err.to_string().contains("point 1"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_derived_views() -> TestResult {
+        let result = fixture()?;
+        let other = fixture()?;
+        let view = result.point_views()?.pop().ok_or("no point view")?;
+
+        let is_ground =
+            |p: &crate::PointRef| p.get::<u8>(DimTypeId::Classification).ok() == Some(2);
+        let ground = view.filter(is_ground)?;
+        let rest = view.filter(|p| !is_ground(p))?;
+        assert!(!ground.is_empty());
+        assert_eq!(ground.len() + rest.len(), view.len());
+        assert!(ground.points().all(|p| is_ground(&p)));
+        assert_eq!(ground.wkt()?, view.wkt()?);
+
+        let all = ground.append(&rest)?;
+        assert_eq!(all.len(), view.len());
+        let foreign = other.point_views()?.pop().ok_or("no point view")?;
+        let err = ground.append(&foreign).unwrap_err();
+        assert!(err.to_string().contains("different point layout"), "{err}");
+
+        let picked = view.subset(&[5, 1])?;
+        assert_eq!(
+            picked.dimension_values::<f64>(DimTypeId::X)?,
+            vec![
+                view.point_value_as::<f64>(DimTypeId::X, 5)?,
+                view.point_value_as::<f64>(DimTypeId::X, 1)?
+            ]
+        );
+        assert!(view.subset(&[view.len() as PointId]).is_err());
+
+        let empty = view.make_new();
+        assert!(empty.is_empty());
+        assert_eq!(
+            empty.layout()?.dimension_count(),
+            view.layout()?.dimension_count()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_point_values() -> TestResult {
         let result = fixture()?;
@@ -129,6 +367,10 @@ mod tests {
 
         let average_intensity = total_intensity / view.len() as f64;
         assert_eq!(average_intensity.floor(), 102.0);
+
+        let intensities = view.dimension_values::<f64>(DimTypeId::Intensity)?;
+        assert_eq!(intensities.len(), view.len());
+        assert_eq!(intensities.iter().sum::<f64>(), total_intensity);
         Ok(())
     }
 }
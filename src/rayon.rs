@@ -0,0 +1,161 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Parallel iteration over point views with [`rayon`].
+
+use crate::error::Result;
+use crate::point::point_dimensions;
+use crate::{Dimension, PdalType, PointId, PointRef, PointView};
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Contiguous, read-only range of points within a [`PointView`].
+///
+/// See [`PointView::par_chunks`].
+#[derive(Debug, Clone, Copy)]
+pub struct PointChunk<'pv> {
+    view: &'pv PointView,
+    start: PointId,
+    len: usize,
+}
+
+impl<'pv> PointChunk<'pv> {
+    /// The point view the chunk belongs to.
+    pub fn view(&self) -> &'pv PointView {
+        self.view
+    }
+
+    /// Range of point IDs covered by the chunk.
+    pub fn range(&self) -> Range<PointId> {
+        self.start..self.start + self.len as PointId
+    }
+
+    /// Number of points in the chunk.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determine if the chunk is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterator over the point IDs in the chunk.
+    pub fn point_ids(&self) -> Range<PointId> {
+        self.range()
+    }
+
+    /// Fetch the values of a dimension for every point in the chunk, in a single call.
+    pub fn values<T: PdalType + Copy + Default>(
+        &self,
+        dim: impl Into<Dimension>,
+    ) -> Result<Vec<T>> {
+        let mut out = vec![T::default(); self.len];
+        self.view.read_dimension_values(dim, self.start, &mut out)?;
+        Ok(out)
+    }
+
+    /// Fetch the dimension value of a single point in the chunk.
+    pub fn value_as<T: PdalType>(&self, dim: impl Into<Dimension>, idx: PointId) -> Result<T> {
+        if !self.range().contains(&idx) {
+            return Err(format!("Point {idx} is outside chunk {:?}", self.range()).into());
+        }
+        self.view.point_value_as(dim, idx)
+    }
+}
+
+impl PointView {
+    /// Parallel iterator over all points in the view.
+    ///
+    /// See [`PointView::points`].
+    pub fn par_points(&self) -> impl IndexedParallelIterator<Item = PointRef<'_>> {
+        let dims = point_dimensions(self);
+        (0..self.len())
+            .into_par_iter()
+            .map(move |i| PointRef::new(self, i as PointId, dims.clone()))
+    }
+
+    /// Parallel iterator over consecutive chunks of at most `chunk_size` points.
+    ///
+    /// Reading whole dimensions per chunk with [`PointChunk::values`] avoids crossing into PDAL
+    /// for every point.
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
+    pub fn par_chunks(
+        &self,
+        chunk_size: usize,
+    ) -> impl IndexedParallelIterator<Item = PointChunk<'_>> {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        let len = self.len();
+        (0..len.div_ceil(chunk_size)).into_par_iter().map(move |i| {
+            let start = i * chunk_size;
+            PointChunk {
+                view: self,
+                start: start as PointId,
+                len: chunk_size.min(len - start),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::{read_test_file, TestResult};
+    use crate::{DimTypeId, Pipeline};
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_par_chunks() -> TestResult {
+        let pipeline = Pipeline::new(read_test_file("stats.json"))?;
+        let results = pipeline.execute()?;
+        let view = results.point_views()?.pop().ok_or("no point view")?;
+
+        let expected: u64 = view
+            .dimension_values::<u16>(DimTypeId::Intensity)?
+            .into_iter()
+            .map(u64::from)
+            .sum();
+
+        let chunks: Vec<_> = view.par_chunks(7000).collect();
+        assert_eq!(chunks.len(), 16);
+        assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), view.len());
+        assert_eq!(chunks[15].range(), 105000..110000);
+
+        let total: u64 = view
+            .par_chunks(7000)
+            .map(|chunk| {
+                let values = chunk.values::<u16>(DimTypeId::Intensity).unwrap();
+                values.into_iter().map(u64::from).sum::<u64>()
+            })
+            .sum();
+        assert_eq!(total, expected);
+
+        let total: u64 = view
+            .par_points()
+            .map(|p| p.get::<u16>(DimTypeId::Intensity).unwrap() as u64)
+            .sum();
+        assert_eq!(total, expected);
+
+        let chunk = chunks[1];
+        assert!(chunk.value_as::<u16>(DimTypeId::Intensity, 7000).is_ok());
+        assert!(chunk.value_as::<u16>(DimTypeId::Intensity, 0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::error::Result;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+
+/// Axis-aligned 3D bounding box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Bounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub min_z: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    pub max_z: f64,
+}
+
+/// Summary of a point cloud read from its header, without loading any points.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QuickInfo {
+    pub point_count: u64,
+    /// `None` if the reader doesn't report bounds.
+    pub bounds: Option<Bounds>,
+    /// Spatial reference as WKT, or `None` if not defined.
+    pub srs_wkt: Option<String>,
+    pub dimension_names: Vec<String>,
+    /// Reader-specific metadata as JSON, or `None` if not provided.
+    pub metadata: Option<String>,
+}
+
+impl From<pdal_sys::reader::QuickInfo> for QuickInfo {
+    fn from(qi: pdal_sys::reader::QuickInfo) -> Self {
+        let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
+        Self {
+            point_count: qi.point_count,
+            bounds: qi.has_bounds.then_some(Bounds {
+                min_x: qi.min_x,
+                min_y: qi.min_y,
+                min_z: qi.min_z,
+                max_x: qi.max_x,
+                max_y: qi.max_y,
+                max_z: qi.max_z,
+            }),
+            srs_wkt: non_empty(qi.srs_wkt),
+            dimension_names: qi.dimension_names,
+            metadata: non_empty(qi.metadata),
+        }
+    }
+}
+
+/// A standalone PDAL reader stage.
+pub struct Reader(pdal_sys::reader::ReaderPtr);
+
+impl Reader {
+    /// Create a reader for `path`, inferring the driver from the file extension.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::with_driver("", path)
+    }
+
+    /// Create a reader for `path` using an explicit driver, e.g. `readers.las`.
+    pub fn with_driver<P: AsRef<Path>>(driver: &str, path: P) -> Result<Self> {
+        let path = path.as_ref().to_string_lossy();
+        Ok(Self(pdal_sys::reader::Reader::new(driver, &path)?))
+    }
+
+    /// Name of the reader driver, e.g. `readers.las`.
+    pub fn driver(&self) -> String {
+        self.0.driver()
+    }
+
+    /// Read header-level information about the point cloud without loading points.
+    pub fn preview(&mut self) -> Result<QuickInfo> {
+        Ok(self.0.pin_mut().preview()?.into())
+    }
+}
+
+impl Debug for Reader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Read header-level information about the point cloud at `path`.
+///
+/// Shorthand for `Reader::new(path)?.preview()`.
+pub fn quick_info<P: AsRef<Path>>(path: P) -> Result<QuickInfo> {
+    Reader::new(path)?.preview()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::{TestResult, DATA_DIR};
+
+    #[test]
+    fn test_quick_info() -> TestResult {
+        let info = quick_info(DATA_DIR.join("autzen_trim.laz"))?;
+        assert_eq!(info.point_count, 110000);
+        let bounds = info.bounds.expect("bounds");
+        assert!(bounds.min_x < bounds.max_x);
+        assert!(bounds.min_y < bounds.max_y);
+        assert!(info.srs_wkt.is_some());
+        for dim in ["X", "Y", "Z", "Intensity"] {
+            assert!(info.dimension_names.iter().any(|n| n == dim), "{dim}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader() -> TestResult {
+        let mut reader = Reader::with_driver("readers.las", DATA_DIR.join("autzen_trim.laz"))?;
+        assert_eq!(reader.driver(), "readers.las");
+        assert_eq!(reader.preview()?.point_count, 110000);
+
+        assert!(Reader::new("points.unknown").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::error::Result;
+use crate::Pipeline;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Pipeline JSON containing `${name}` placeholders within string values.
+///
+/// A string consisting solely of a placeholder is replaced by the bound JSON value, allowing
+/// numbers, arrays, etc. to be substituted. Placeholders embedded in longer strings are replaced
+/// by the text of the bound value. Use `$${` for a literal `${`.
+///
+/// ```no_run
+/// use pdal::PipelineTemplate;
+/// let template = PipelineTemplate::new(
+///     r#"["${input}", {"type": "filters.crop", "bounds": "${bounds}"}, "${input}.out.las"]"#,
+/// )?;
+/// let pipeline = template.render([
+///     ("input", "tile_001.las"),
+///     ("bounds", "([0, 100], [0, 100])"),
+/// ])?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PipelineTemplate {
+    json: Value,
+    placeholders: BTreeSet<String>,
+}
+
+impl PipelineTemplate {
+    /// Parse a pipeline template.
+    pub fn new<J: AsRef<str>>(template: J) -> Result<Self> {
+        let json: Value = serde_json::from_str(template.as_ref())?;
+        let mut placeholders = BTreeSet::new();
+        visit_strings(&json, &mut |s| {
+            for segment in parse(s)? {
+                if let Segment::Placeholder(name) = segment {
+                    placeholders.insert(name.to_string());
+                }
+            }
+            Ok(())
+        })?;
+        Ok(Self { json, placeholders })
+    }
+
+    /// Names of the placeholders in the template.
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.placeholders.iter().map(String::as_str)
+    }
+
+    /// Substitute `params` into the template, producing pipeline JSON.
+    ///
+    /// Fails if a placeholder is left unbound or a parameter doesn't match any placeholder.
+    pub fn render_json<I, K, V>(&self, params: I) -> Result<String>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        let params: BTreeMap<String, Value> = params
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+
+        let unbound: Vec<_> = self
+            .placeholders()
+            .filter(|p| !params.contains_key(*p))
+            .collect();
+        if !unbound.is_empty() {
+            return Err(format!("Unbound template parameters: {}", unbound.join(", ")).into());
+        }
+        let unknown: Vec<_> = params
+            .keys()
+            .filter(|k| !self.placeholders.contains(*k))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Err(format!("Unknown template parameters: {}", unknown.join(", ")).into());
+        }
+
+        let rendered = substitute(&self.json, &params)?;
+        Ok(serde_json::to_string(&rendered)?)
+    }
+
+    /// Substitute `params` into the template and parse the result as a [`Pipeline`].
+    ///
+    /// See [`PipelineTemplate::render_json`].
+    pub fn render<I, K, V>(&self, params: I) -> Result<Pipeline>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        Pipeline::new(self.render_json(params)?)
+    }
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+/// Split a string into literal text and placeholder names.
+fn parse(s: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find('$') {
+        let (text, tail) = rest.split_at(start);
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+        if let Some(tail) = tail.strip_prefix("$${") {
+            segments.push(Segment::Text("${"));
+            rest = tail;
+        } else if let Some(tail) = tail.strip_prefix("${") {
+            let end = tail
+                .find('}')
+                .ok_or_else(|| format!("Unterminated placeholder in '{s}'"))?;
+            let name = &tail[..end];
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("Invalid placeholder name '{name}' in '{s}'").into());
+            }
+            segments.push(Segment::Placeholder(name));
+            rest = &tail[end + 1..];
+        } else {
+            segments.push(Segment::Text("$"));
+            rest = &tail[1..];
+        }
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    Ok(segments)
+}
+
+fn visit_strings<F>(value: &Value, f: &mut F) -> Result<()>
+where
+    F: FnMut(&str) -> Result<()>,
+{
+    match value {
+        Value::String(s) => f(s),
+        Value::Array(items) => items.iter().try_for_each(|v| visit_strings(v, f)),
+        Value::Object(fields) => fields.values().try_for_each(|v| visit_strings(v, f)),
+        _ => Ok(()),
+    }
+}
+
+fn substitute(value: &Value, params: &BTreeMap<String, Value>) -> Result<Value> {
+    Ok(match value {
+        Value::String(s) => {
+            let segments = parse(s)?;
+            match segments.as_slice() {
+                [Segment::Placeholder(name)] => params[*name].clone(),
+                _ => {
+                    let mut out = String::new();
+                    for segment in segments {
+                        match segment {
+                            Segment::Text(text) => out.push_str(text),
+                            Segment::Placeholder(name) => match &params[name] {
+                                Value::String(v) => out.push_str(v),
+                                v @ (Value::Number(_) | Value::Bool(_)) => {
+                                    out.push_str(&v.to_string())
+                                }
+                                _ => {
+                                    return Err(format!(
+                                        "Parameter '{name}' can't be embedded in '{s}'"
+                                    )
+                                    .into())
+                                }
+                            },
+                        }
+                    }
+                    Value::String(out)
+                }
+            }
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|v| substitute(v, params))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), substitute(v, params)?)))
+                .collect::<Result<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::TestResult;
+    use serde_json::json;
+
+    #[test]
+    fn test_render() -> TestResult {
+        let template = PipelineTemplate::new(
+            r#"{ "pipeline": [
+                "${input}",
+                { "type": "filters.range", "limits": "Intensity[${min}:]" },
+                { "type": "filters.crop", "bounds": "${bounds}" },
+                { "type": "writers.null", "comment": "$${literal}" }
+            ] }"#,
+        )?;
+        assert_eq!(
+            template.placeholders().collect::<Vec<_>>(),
+            vec!["bounds", "input", "min"]
+        );
+
+        let json = template.render_json([
+            ("input", json!("tests/data/autzen \"trim\".laz")),
+            ("min", json!(100)),
+            ("bounds", json!("([0, 1], [0, 1])")),
+        ])?;
+        let value: Value = serde_json::from_str(&json)?;
+        assert_eq!(value["pipeline"][0], "tests/data/autzen \"trim\".laz");
+        assert_eq!(value["pipeline"][1]["limits"], "Intensity[100:]");
+        assert_eq!(value["pipeline"][3]["comment"], "${literal}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_pipeline() -> TestResult {
+        let template = PipelineTemplate::new(r#"["${input}", {"type": "writers.null"}]"#)?;
+        let result = template
+            .render([("input", "tests/data/autzen_trim.laz")])?
+            .execute()?;
+        assert_eq!(result.point_count(), 110000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_params() -> TestResult {
+        let template = PipelineTemplate::new(r#"["${input}", "${output}"]"#)?;
+        let err = template.render_json([("input", "a.las")]).unwrap_err();
+        assert!(err.to_string().contains("output"));
+        let err = template
+            .render_json([("input", "a.las"), ("output", "b.las"), ("extra", "c")])
+            .unwrap_err();
+        assert!(err.to_string().contains("extra"));
+
+        assert!(PipelineTemplate::new(r#"["${input"]"#).is_err());
+        assert!(PipelineTemplate::new(r#"["${in put}"]"#).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// MIT License
+//
+// Copyright (c) 2024 NUVIEW, Inc. <simeon.fitch@nuview.space>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software
+// and associated documentation files (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge, publish, distribute,
+// sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or
+// substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING
+// BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM,
+// DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT
+// OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Asynchronous pipeline execution for [`tokio`] based applications.
+
+use crate::error::Result;
+use crate::{ExecMode, ExecutedPipeline, Pipeline};
+use pdal_sys::pipeline_manager::ExecutionMonitorPtr;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::time::Interval;
+
+/// How often progress and log events are reported.
+const REPORT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Event reported while a pipeline executes via [`Pipeline::execute_async_with`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecEvent {
+    /// Points produced so far by each stage, ordered as [`Pipeline::stages`].
+    Progress(Vec<u64>),
+    /// A line written to the PDAL log, which is redirected from its usual destination while
+    /// events are reported.
+    Log(String),
+}
+
+impl Pipeline {
+    /// Execute the pipeline on tokio's blocking thread pool.
+    ///
+    /// See [`Pipeline::execute_async_with`].
+    pub async fn execute_async(self) -> Result<ExecutedPipeline> {
+        self.execute_async_with(ExecMode::Standard, None).await
+    }
+
+    /// Execute the pipeline in the given mode on tokio's blocking thread pool, optionally
+    /// reporting progress and log lines to `events`.
+    ///
+    /// Dropping the returned future cancels execution, which stops at the next point processed.
+    /// Reporting events requires the tokio time driver.
+    pub async fn execute_async_with(
+        mut self,
+        mode: ExecMode,
+        events: Option<UnboundedSender<ExecEvent>>,
+    ) -> Result<ExecutedPipeline> {
+        let monitor = self.monitor();
+        if events.is_some() {
+            self.capture_log();
+        }
+        let mut guard = CancelOnDrop(Some(monitor.clone()));
+
+        // `Error` isn't `Send`, so errors cross the thread boundary as strings.
+        let mut task =
+            tokio::task::spawn_blocking(move || self.execute_with(mode).map_err(|e| e.to_string()));
+        let mut ticker = events
+            .as_ref()
+            .map(|_| tokio::time::interval(REPORT_INTERVAL));
+
+        let result = loop {
+            tokio::select! {
+                result = &mut task => break result,
+                _ = tick(&mut ticker) => report(&monitor, events.as_ref()),
+            }
+        };
+        report(&monitor, events.as_ref());
+        guard.0 = None;
+
+        Ok(result??)
+    }
+}
+
+async fn tick(ticker: &mut Option<Interval>) {
+    match ticker {
+        Some(ticker) => {
+            ticker.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+fn report(monitor: &ExecutionMonitorPtr, events: Option<&UnboundedSender<ExecEvent>>) {
+    if let Some(events) = events {
+        for line in monitor.drainLog() {
+            let _ = events.send(ExecEvent::Log(line));
+        }
+        let _ = events.send(ExecEvent::Progress(monitor.stagePoints()));
+    }
+}
+
+/// Cancels execution if the owning future is dropped before completion.
+struct CancelOnDrop(Option<ExecutionMonitorPtr>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(monitor) = &self.0 {
+            monitor.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::{read_test_file, TestResult};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_execute_async() -> TestResult {
+        let pipeline = Pipeline::new(read_test_file("stats.json"))?;
+        let result = pipeline.execute_async().await?;
+        assert_eq!(result.point_count(), 110000);
+        assert_eq!(result.point_views()?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_async_events() -> TestResult {
+        let pipeline = Pipeline::new(read_test_file("stats.json"))?;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let result = pipeline
+            .execute_async_with(ExecMode::Stream, Some(tx))
+            .await?;
+        assert!(result.is_streamed());
+
+        let mut progress = None;
+        while let Some(event) = rx.recv().await {
+            if let ExecEvent::Progress(p) = event {
+                progress = Some(p);
+            }
+        }
+        assert_eq!(progress.expect("progress"), vec![110000; 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        let pipeline = Pipeline::new(read_test_file("stats.json")).unwrap();
+        assert_send(&pipeline.execute_async());
+    }
+}